@@ -3,6 +3,9 @@
 //!
 //! It is implemented for any type which implements `Iterator`, but you can implement it to your custom iterator.
 //!
+//! The hash-based methods need the `std` feature, enabled by default. Without it the crate is `no_std`
+//! and only the methods which just need `alloc`, like `grouping_by_ordered` or `counter_ordered`, are available.
+//!
 //! ## Example:
 //! ```rust
 //! use std::collections::HashMap;
@@ -20,6 +23,7 @@
 //!        Point { x: 2, y: 2 },
 //! ];
 //!
+//! # #[cfg(feature = "std")]
 //! assert_eq!(
 //!     [
 //!         (1, vec![&Point { x: 1, y: 2 }, &Point { x: 1, y: 3 }]),
@@ -34,11 +38,25 @@
 // TODO
 // Implement two argument grouping by, just like groupingBy of Java does
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::collections::{BTreeMap, BTreeSet};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use std::collections::{
     hash_map::{Entry, HashMap},
-    HashSet,
+    BinaryHeap, HashSet, VecDeque,
 };
-use std::hash::Hash;
+#[cfg(feature = "std")]
+use std::hash::{BuildHasher, Hash};
+
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(feature = "futures")]
+pub use stream::{GroupingByStream, GroupingByStreamFuture};
 
 pub trait GroupingBy {
     /// The type of the Item of the iterator
@@ -65,6 +83,7 @@ pub trait GroupingBy {
     ///         .collect::<HashMap<i8, HashSet<&i8>>>()
     /// );
     /// ```
+    #[cfg(feature = "std")]
     fn grouping_by<K, F>(self, key: F) -> HashMap<K, Vec<Self::GItem>>
     where
         F: FnMut(&Self::GItem) -> K,
@@ -91,6 +110,7 @@ pub trait GroupingBy {
     ///         .collect()
     /// );
     /// ```
+    #[cfg(feature = "std")]
     fn grouping_by_as_set<K, F>(self, key: F) -> HashMap<K, HashSet<Self::GItem>>
     where
         Self::GItem: Eq + Hash,
@@ -113,6 +133,7 @@ pub trait GroupingBy {
     ///        .collect::<HashMap<i8, usize>>()
     /// )
     /// ```
+    #[cfg(feature = "std")]
     fn counter<K, F>(self, key: F) -> HashMap<K, usize>
     where
         K: Eq + Hash,
@@ -154,6 +175,7 @@ pub trait GroupingBy {
     /// );
     /// assert_eq!(a, [(3, &Vector { x: 1, y: 3, z: 3 } ), (2, &Vector { x: 2, y: 2, z: 2 })].iter().cloned().collect())
     /// ```
+    #[cfg(feature = "std")]
     fn grouping_by_max<K, F, C>(self, key: F, comparator: C) -> HashMap<K, Self::GItem>
     where
         K: Eq + Hash,
@@ -196,6 +218,7 @@ pub trait GroupingBy {
     /// );
     /// assert_eq!(a, [(3, &Vector { x: 1, y: 3, z: 3 } ), (2, &Vector { x: 1, y: 2, z: 4 })].iter().cloned().collect())
     /// ```
+    #[cfg(feature = "std")]
     fn grouping_by_min<K, F, C>(self, key: F, comparator: C) -> HashMap<K, Self::GItem>
     where
         K: Eq + Hash,
@@ -233,116 +256,5323 @@ pub trait GroupingBy {
     /// );
     /// assert_eq!(a, [(2, 4), (1, 5)].iter().cloned().collect())
     /// ```
+    #[cfg(feature = "std")]
     fn summing<K, V, F, G>(self, key: F, sum_func: G) -> HashMap<K, V>
     where
         K: Eq + Hash,
         F: FnMut(&Self::GItem) -> K,
         G: FnMut(&Self::GItem) -> V,
         V: Default + std::ops::AddAssign;
-}
-
-mod utilities {
-    use super::{Entry, Hash, HashMap};
 
-    pub fn grouping_by_min_max_aux<T, K, F, C>(
-        iterator: T,
-        mut key: F,
-        mut comparator: C,
-        type_ord: std::cmp::Ordering,
-    ) -> HashMap<K, T::Item>
+    /// Fold the items of every group into an accumulator with a fallible function.
+    /// Each group starts from the value returned by `init` and the first error returned by `fold`
+    /// aborts the iteration and is propagated.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `init` -> function to create the initial accumulator of each group
+    ///
+    /// `fold` -> function to fold an item into the accumulator of its group
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let sums = [1u8, 2, 3, 4]
+    ///     .iter()
+    ///     .grouping_by_try_fold(|&&x| x % 2, || 0u8, |acc, &x| acc.checked_add(x).ok_or(x));
+    ///
+    /// assert_eq!(sums, Ok([(0, 6), (1, 4)].iter().cloned().collect::<HashMap<u8, u8>>()));
+    ///
+    /// let overflow = [100u8, 100, 100]
+    ///     .iter()
+    ///     .grouping_by_try_fold(|_| (), || 0u8, |acc, &x| acc.checked_add(x).ok_or(x));
+    ///
+    /// assert_eq!(overflow, Err(100));
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_try_fold<K, A, E, F, I, Fold>(
+        self,
+        key: F,
+        init: I,
+        fold: Fold,
+    ) -> Result<HashMap<K, A>, E>
     where
-        T: Iterator,
         K: Eq + Hash,
-        F: FnMut(&T::Item) -> K,
-        C: FnMut(&T::Item, &T::Item) -> std::cmp::Ordering,
-    {
-        let mut map = HashMap::new();
-        iterator.for_each(|item| {
-            let key = key(&item);
-            match map.entry(key) {
-                Entry::Occupied(mut entry) => {
-                    if comparator(&item, entry.get()) == type_ord {
-                        entry.insert(item);
-                    }
-                }
-                Entry::Vacant(entry) => {
-                    entry.insert(item);
-                }
-            }
-        });
-        map
-    }
-}
+        F: FnMut(&Self::GItem) -> K,
+        I: FnMut() -> A,
+        Fold: FnMut(A, Self::GItem) -> Result<A, E>;
 
-impl<T: Iterator> GroupingBy for T {
-    type GItem = T::Item;
-    fn grouping_by<K, F>(self, mut key: F) -> HashMap<K, Vec<Self::GItem>>
+    /// Given a functions F and C compute both the minimum and the maximum of the elements of every group
+    /// in a single iteration. The values of the resulting map are `(min, max)` tuples.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `comparator` -> function to get the min and max values
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let min_max = [1, 5, 2, 8, 3].iter().grouping_by_min_max(|&&x| x % 2, |a, b| a.cmp(b));
+    ///
+    /// assert_eq!(
+    ///     min_max,
+    ///     [(0, (&2, &8)), (1, (&1, &5))]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<i32, (&i32, &i32)>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_min_max<K, F, C>(
+        self,
+        key: F,
+        comparator: C,
+    ) -> HashMap<K, (Self::GItem, Self::GItem)>
     where
+        Self::GItem: Clone,
+        K: Eq + Hash,
         F: FnMut(&Self::GItem) -> K,
+        C: FnMut(&Self::GItem, &Self::GItem) -> std::cmp::Ordering;
+
+    /// Compute the share of the total number of elements that every group represents.
+    /// The values are the counts of `counter` divided by the total, so they sum to `1.0`.
+    /// An empty iterator returns an empty map.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let fractions = [1, 2, 2, 4].iter().grouping_by_fractions(|&&x| x);
+    ///
+    /// assert_eq!(
+    ///    fractions,
+    ///    [(1, 0.25), (2, 0.5), (4, 0.25)]
+    ///        .iter()
+    ///        .cloned()
+    ///        .collect::<HashMap<i8, f64>>()
+    /// )
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_fractions<K, F>(self, key: F) -> HashMap<K, f64>
+    where
         K: Eq + Hash,
-    {
-        let mut map = HashMap::new();
-        self.for_each(|item| {
-            map.entry(key(&item)).or_insert_with(Vec::new).push(item);
-        });
-        map
-    }
-    fn grouping_by_as_set<K, F>(self, mut key: F) -> HashMap<K, HashSet<Self::GItem>>
+        F: FnMut(&Self::GItem) -> K;
+
+    /// Group by the key function given as parameter, just like `grouping_by`,
+    /// but returning a `BTreeMap` so the groups are iterated in ascending order of their keys.
+    /// It only needs `alloc`, so it is available without the `std` feature.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let groups = [3, 1, 4, 1, 5].iter().grouping_by_ordered(|&&x| x % 2 == 0);
+    ///
+    /// assert_eq!(
+    ///    groups.into_iter().collect::<Vec<(bool, Vec<&i32>)>>(),
+    ///    vec![(false, vec![&3, &1, &1, &5]), (true, vec![&4])]
+    /// )
+    /// ```
+    fn grouping_by_ordered<K, F>(self, key: F) -> BTreeMap<K, Vec<Self::GItem>>
+    where
+        K: Ord,
+        F: FnMut(&Self::GItem) -> K;
+
+    /// Count the elements of the iterator given a function, just like `counter`,
+    /// but returning a `BTreeMap` so the keys are iterated in ascending order
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let numbers_counted = [4, 2, 1, 2, 3].iter().counter_ordered(|&&x| x);
+    ///
+    /// assert_eq!(
+    ///    numbers_counted.into_iter().collect::<Vec<(i8, usize)>>(),
+    ///    vec![(1, 1), (2, 2), (3, 1), (4, 1)]
+    /// )
+    /// ```
+    fn counter_ordered<K, F>(self, key: F) -> BTreeMap<K, usize>
+    where
+        K: Ord,
+        F: FnMut(&Self::GItem) -> K;
+
+    /// Create a map with one value per key, just like Java `Collectors.toMap`.
+    /// When two items have the same key, their values are combined with the `merge` function,
+    /// which receives the value already stored and the new one.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to create the values of the resulting map
+    ///
+    /// `merge` -> function to combine the old and the new value of a repeated key
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let map = ["a", "bb", "cc", "ddd"]
+    ///     .iter()
+    ///     .to_map(|s| s.len(), |s| s.to_string(), |old, new| old + &new);
+    ///
+    /// assert_eq!(
+    ///     map,
+    ///     [(1, "a".to_string()), (2, "bbcc".to_string()), (3, "ddd".to_string())]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<usize, String>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn to_map<K, V, KF, VF, M>(self, key: KF, value: VF, merge: M) -> HashMap<K, V>
     where
-        Self::GItem: Eq + Hash,
-        F: FnMut(&Self::GItem) -> K,
         K: Eq + Hash,
-    {
-        let mut map = HashMap::new();
-        self.for_each(|item| {
-            map.entry(key(&item))
-                .or_insert_with(HashSet::new)
-                .insert(item);
-        });
-        map
-    }
-    fn counter<K, F>(self, mut key: F) -> HashMap<K, usize>
+        KF: FnMut(&Self::GItem) -> K,
+        VF: FnMut(&Self::GItem) -> V,
+        M: FnMut(V, V) -> V;
+
+    /// Create a map with one value per key, failing on the first repeated key.
+    /// The error contains the repeated key, the value already stored and the new one.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to create the values of the resulting map
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let unique = [1, 2, 3].iter().try_to_map(|&&x| x, |&&x| x * 10);
+    /// assert_eq!(
+    ///     unique,
+    ///     Ok([(1, 10), (2, 20), (3, 30)].iter().cloned().collect::<HashMap<i32, i32>>())
+    /// );
+    ///
+    /// let repeated = [1, 2, 3].iter().try_to_map(|&&x| x % 2, |&&x| x);
+    /// assert_eq!(repeated, Err((1, 1, 3)));
+    /// ```
+    #[cfg(feature = "std")]
+    fn try_to_map<K, V, KF, VF>(self, key: KF, value: VF) -> Result<HashMap<K, V>, (K, V, V)>
     where
         K: Eq + Hash,
+        KF: FnMut(&Self::GItem) -> K,
+        VF: FnMut(&Self::GItem) -> V;
+
+    /// Group the positions of the elements of the iterator by the key function given as parameter.
+    /// The values are a `Vec` with the ascending indices of the items which has the key as property
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let indices = [-1i8, -2, 1, 2].iter().grouping_by_index(|number| number.abs());
+    ///
+    /// assert_eq!(
+    ///     indices,
+    ///     [(1, vec![0, 2]), (2, vec![1, 3])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<i8, Vec<usize>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_index<K, F>(self, key: F) -> HashMap<K, Vec<usize>>
+    where
         F: FnMut(&Self::GItem) -> K,
-    {
-        let mut map = HashMap::new();
-        self.for_each(|item| {
-            *map.entry(key(&item)).or_insert(0) += 1;
-        });
-        map
-    }
+        K: Eq + Hash;
 
-    fn grouping_by_max<K, F, C>(self, key: F, comparator: C) -> HashMap<K, Self::GItem>
+    /// Group by the key function given as parameter, keeping the position of every item.
+    /// The values are a `Vec` with `(index, item)` pairs in ascending order of index
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let enumerated = [-1i8, -2, 1, 2].iter().grouping_by_enumerate(|number| number.abs());
+    ///
+    /// assert_eq!(
+    ///     enumerated,
+    ///     [(1, vec![(0, &-1), (2, &1)]), (2, vec![(1, &-2), (3, &2)])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<i8, Vec<(usize, &i8)>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_enumerate<K, F>(self, key: F) -> HashMap<K, Vec<(usize, Self::GItem)>>
     where
-        K: Eq + Hash,
         F: FnMut(&Self::GItem) -> K,
-        C: FnMut(&Self::GItem, &Self::GItem) -> std::cmp::Ordering,
-    {
-        utilities::grouping_by_min_max_aux(self, key, comparator, std::cmp::Ordering::Greater)
-    }
+        K: Eq + Hash;
 
-    fn grouping_by_min<K, F, C>(self, key: F, comparator: C) -> HashMap<K, Self::GItem>
+    /// Group by the key function given as parameter, appending the items into an existing map
+    /// instead of creating a new one. The items are pushed to the end of the group of their key,
+    /// which is created if it doesn't exist yet.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let mut map = [-1i8, 2].iter().grouping_by(|number| number.abs());
+    /// [1i8, 3].iter().grouping_by_into_existing(&mut map, |number| number.abs());
+    ///
+    /// assert_eq!(
+    ///     map,
+    ///     [(1, vec![&-1, &1]), (2, vec![&2]), (3, vec![&3])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<i8, Vec<&i8>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_into_existing<K, F>(self, map: &mut HashMap<K, Vec<Self::GItem>>, key: F)
     where
-        K: Eq + Hash,
         F: FnMut(&Self::GItem) -> K,
-        C: FnMut(&Self::GItem, &Self::GItem) -> std::cmp::Ordering,
-    {
-        utilities::grouping_by_min_max_aux(self, key, comparator, std::cmp::Ordering::Less)
-    }
+        K: Eq + Hash;
 
-    fn summing<K, V, F, G>(self, mut key: F, mut value: G) -> HashMap<K, V>
+    /// Keep the `n` smallest elements of every group, compared by the value returned by `f`.
+    /// Only `n` items per group are stored while iterating, and each group is sorted in ascending order.
+    /// When several items have the same value, the first ones in the iterator are kept.
+    /// If `n` is 0, every key is present with an empty group.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `n` -> maximum number of items of every group
+    ///
+    /// `f` -> function to get the value by which the items are compared
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let smallest = [5, 3, 8, 1, 4, 6, 2]
+    ///     .iter()
+    ///     .grouping_by_min_n_by_key(|&&x| x % 2, 2, |&&x| x);
+    ///
+    /// assert_eq!(
+    ///     smallest,
+    ///     [(0, vec![&2, &4]), (1, vec![&1, &3])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<i32, Vec<&i32>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_min_n_by_key<K, B, F, G>(
+        self,
+        key: F,
+        n: usize,
+        f: G,
+    ) -> HashMap<K, Vec<Self::GItem>>
     where
         K: Eq + Hash,
+        B: Ord,
         F: FnMut(&Self::GItem) -> K,
-        G: FnMut(&Self::GItem) -> V,
-        V: Default + std::ops::AddAssign,
-    {
-        let mut map: HashMap<K, V> = HashMap::new();
-        self.for_each(|item| {
-            let v = map.entry(key(&item)).or_default();
-            *v += value(&item);
-        });
-        map
+        G: FnMut(&Self::GItem) -> B;
+
+    /// Group by the key function given as parameter, which may not classify every item.
+    /// The items whose key is `None` are dropped, and the rest are grouped by the key inside the `Some`
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let numbers_grouped = [-1i8, 0, 1, 2]
+    ///     .iter()
+    ///     .grouping_by_skip_none(|&&number| if number != 0 { Some(number.signum()) } else { None });
+    ///
+    /// assert_eq!(
+    ///     numbers_grouped,
+    ///     [(-1, vec![&-1]), (1, vec![&1, &2])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<i8, Vec<&i8>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_skip_none<K, F>(self, key: F) -> HashMap<K, Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> Option<K>,
+        K: Eq + Hash;
+
+    /// Return a map containing the weighted average of the values of every group,
+    /// computed as `sum(value * weight) / sum(weight)` in a single iteration.
+    /// If the total weight of a group is zero, its average is `f64::NAN`.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to average
+    ///
+    /// `weight` -> function to get the weight of every value
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// // (group, price, quantity)
+    /// let orders = [("a", 10.0, 1.0), ("a", 20.0, 3.0), ("b", 5.0, 2.0)];
+    ///
+    /// let averages = orders
+    ///     .iter()
+    ///     .grouping_by_weighted_average(|order| order.0, |order| order.1, |order| order.2);
+    ///
+    /// assert_eq!(
+    ///     averages,
+    ///     [("a", 17.5), ("b", 5.0)]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<&str, f64>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_weighted_average<K, F, VF, WF>(
+        self,
+        key: F,
+        value: VF,
+        weight: WF,
+    ) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        VF: FnMut(&Self::GItem) -> f64,
+        WF: FnMut(&Self::GItem) -> f64;
+
+    /// Count the elements of the iterator given a function, just like `counter`,
+    /// but using `hasher` to hash the keys of the resulting map
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::{hash_map::DefaultHasher, HashMap};
+    /// # use std::hash::BuildHasherDefault;
+    /// let numbers_counted = [1, 2, 2, 3]
+    ///     .iter()
+    ///     .counter_with_hasher(|&&x| x, BuildHasherDefault::<DefaultHasher>::default());
+    ///
+    /// assert_eq!(numbers_counted[&2], 2);
+    /// ```
+    #[cfg(feature = "std")]
+    fn counter_with_hasher<K, F, S>(self, key: F, hasher: S) -> HashMap<K, usize, S>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        S: BuildHasher;
+
+    /// Group by the key function given as parameter, just like `grouping_by_as_set`,
+    /// but using `hasher` to hash both the keys of the resulting map and the items of every `HashSet`
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::hash_map::DefaultHasher;
+    /// # use std::hash::BuildHasherDefault;
+    /// let numbers_grouped = [-1i8, -2, 1, 2, 2]
+    ///     .iter()
+    ///     .grouping_by_as_set_with_hasher(
+    ///         |number| number.abs(),
+    ///         BuildHasherDefault::<DefaultHasher>::default(),
+    ///     );
+    ///
+    /// assert_eq!(numbers_grouped[&2].len(), 2);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_as_set_with_hasher<K, F, S>(
+        self,
+        key: F,
+        hasher: S,
+    ) -> HashMap<K, HashSet<Self::GItem, S>, S>
+    where
+        Self::GItem: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+        S: BuildHasher + Clone;
+
+    /// Given a functions F and C compute all the elements of every group which are tied for the maximum.
+    /// When a new maximum appears, the items collected so far for its group are discarded.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `comparator` -> function to get the max values
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let words = ["bb", "a", "cc", "ddd", "ee"];
+    ///
+    /// let longest = words.iter().grouping_by_all_max(
+    ///     |word| word.contains('d'),
+    ///     |word1, word2| word1.len().cmp(&word2.len())
+    /// );
+    ///
+    /// assert_eq!(
+    ///     longest,
+    ///     [(false, vec![&"bb", &"cc", &"ee"]), (true, vec![&"ddd"])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<bool, Vec<&&str>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_all_max<K, F, C>(self, key: F, comparator: C) -> HashMap<K, Vec<Self::GItem>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        C: FnMut(&Self::GItem, &Self::GItem) -> std::cmp::Ordering;
+
+    /// Given a functions F and C compute all the elements of every group which are tied for the minimum.
+    /// When a new minimum appears, the items collected so far for its group are discarded.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `comparator` -> function to get the min values
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// // (shop, price)
+    /// let offers = [("a", 5), ("a", 3), ("b", 7), ("a", 3)];
+    ///
+    /// let cheapest = offers.iter().grouping_by_all_min(
+    ///     |offer| offer.0,
+    ///     |offer1, offer2| offer1.1.cmp(&offer2.1)
+    /// );
+    ///
+    /// assert_eq!(
+    ///     cheapest,
+    ///     [("a", vec![&("a", 3), &("a", 3)]), ("b", vec![&("b", 7)])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<&str, Vec<&(&str, i32)>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_all_min<K, F, C>(self, key: F, comparator: C) -> HashMap<K, Vec<Self::GItem>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        C: FnMut(&Self::GItem, &Self::GItem) -> std::cmp::Ordering;
+
+    /// Count the elements of the iterator grouped by two nested key functions.
+    /// The inner maps only contain the inner keys seen under their outer key.
+    ///
+    /// Params:
+    ///
+    /// `outer` -> function to create the keys of the resulting map
+    ///
+    /// `inner` -> function to create the keys of the nested maps
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let counts = [1, 2, 3, 4, 6].iter().grouping_by_then_counting(|&&x| x % 2, |&&x| x > 2);
+    ///
+    /// assert_eq!(counts[&0][&true], 2);
+    /// assert_eq!(counts[&0][&false], 1);
+    /// assert_eq!(counts[&1][&false], 1);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_then_counting<K1, K2, F, G>(
+        self,
+        outer: F,
+        inner: G,
+    ) -> HashMap<K1, HashMap<K2, usize>>
+    where
+        K1: Eq + Hash,
+        K2: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K1,
+        G: FnMut(&Self::GItem) -> K2;
+
+    /// Return a map containing the sum of the values of the elements grouped by two nested key functions.
+    ///
+    /// Params:
+    ///
+    /// `outer` -> function to create the keys of the resulting map
+    ///
+    /// `inner` -> function to create the keys of the nested maps
+    ///
+    /// `value` -> function to get the values to sum
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// // (region, product, sales)
+    /// let sales = [("north", "a", 3), ("north", "a", 2), ("north", "b", 1), ("south", "a", 4)];
+    ///
+    /// let sums = sales.iter().grouping_by_then_summing(|s| s.0, |s| s.1, |s| s.2);
+    ///
+    /// assert_eq!(sums["north"]["a"], 5);
+    /// assert_eq!(sums["north"]["b"], 1);
+    /// assert_eq!(sums["south"]["a"], 4);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_then_summing<K1, K2, V, F, G, H>(
+        self,
+        outer: F,
+        inner: G,
+        value: H,
+    ) -> HashMap<K1, HashMap<K2, V>>
+    where
+        K1: Eq + Hash,
+        K2: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K1,
+        G: FnMut(&Self::GItem) -> K2,
+        H: FnMut(&Self::GItem) -> V,
+        V: Default + std::ops::AddAssign;
+
+    /// Build a histogram of the values returned by `value` with `bins` bins of equal width,
+    /// from the minimum to the maximum value. Returns the `(start, end)` edges of every bin
+    /// and a map with the index of every non-empty bin and its number of values.
+    /// The last bin includes its end edge.
+    ///
+    /// The edges depend on the minimum and maximum, which are only known at the end, so every value is kept until then.
+    /// If all the values are equal, a single bin is returned, and an empty iterator returns no bins.
+    /// The values which aren't finite, `NaN` and the infinities, are ignored, as they have no bin.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bins` is 0.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let (edges, counts) = [0.0, 1.0, 2.5, 3.0, 4.0].iter().grouping_by_histogram(|&&x| x, 2);
+    ///
+    /// assert_eq!(edges, vec![(0.0, 2.0), (2.0, 4.0)]);
+    /// assert_eq!(counts, [(0, 2), (1, 3)].iter().cloned().collect::<HashMap<usize, usize>>());
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_histogram<F>(
+        self,
+        value: F,
+        bins: usize,
+    ) -> (Vec<(f64, f64)>, HashMap<usize, usize>)
+    where
+        F: FnMut(&Self::GItem) -> f64;
+
+    /// Compute the requested quantiles of the values of every group.
+    /// The quantiles are computed by linear interpolation between the two closest order statistics:
+    /// for a quantile `q` of `n` sorted values, the position `q * (n - 1)` is interpolated
+    /// between the values at its floor and ceil. The quantiles are returned in the order of `qs`.
+    ///
+    /// Every group keeps all its values, which are sorted once the iterator is exhausted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the quantiles is not in `0.0..=1.0`.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values of the quantiles
+    ///
+    /// `qs` -> quantiles to compute
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let quantiles = [1.0, 3.0, 2.0, 4.0]
+    ///     .iter()
+    ///     .grouping_by_quantiles(|_| (), |&&x| x, &[0.0, 0.5, 1.0]);
+    ///
+    /// assert_eq!(quantiles[&()], vec![1.0, 2.5, 4.0]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_quantiles<K, F, G>(self, key: F, value: G, qs: &[f64]) -> HashMap<K, Vec<f64>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64;
+
+    /// Group an iterator of pairs by the key function given as parameter, splitting
+    /// the left and right components of the items of every group into two `Vec`s,
+    /// just like `Iterator::unzip`. Both `Vec`s of a group are index-aligned.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let unzipped = vec![('a', 1), ('b', 2), ('a', 3)]
+    ///     .into_iter()
+    ///     .grouping_by_unzip(|pair| pair.0);
+    ///
+    /// assert_eq!(
+    ///     unzipped,
+    ///     [('a', (vec!['a', 'a'], vec![1, 3])), ('b', (vec!['b'], vec![2]))]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<char, (Vec<char>, Vec<i32>)>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_unzip<K, L, R, F>(self, key: F) -> HashMap<K, (Vec<L>, Vec<R>)>
+    where
+        Self: Iterator<Item = (L, R)>,
+        F: FnMut(&(L, R)) -> K,
+        K: Eq + Hash;
+
+    /// Count the elements of the iterator which satisfy the predicate and the ones which don't.
+    /// Returns `(true_count, false_count)`, which are both 0 for an empty iterator.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let counts = [1, 2, 3, 4, 5].iter().partitioning_by_counting(|&&x| x % 2 == 0);
+    ///
+    /// assert_eq!(counts, (2, 3));
+    /// ```
+    fn partitioning_by_counting<F>(self, predicate: F) -> (usize, usize)
+    where
+        F: FnMut(&Self::GItem) -> bool;
+
+    /// Compute both the minimum and the maximum of the elements of every group in a single iteration,
+    /// compared by the value returned by `f`. On ties, the first element of the iterator is kept.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `f` -> function to get the value by which the items are compared
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::{GroupingBy, MinMax};
+    /// let words = ["bb", "a", "ccc"];
+    ///
+    /// let min_max = words.iter().grouping_by_min_max_by_key(|_| (), |word| word.len());
+    ///
+    /// assert_eq!(min_max[&()], MinMax { min: &"a", max: &"ccc" });
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_min_max_by_key<K, B, F, G>(
+        self,
+        key: F,
+        f: G,
+    ) -> HashMap<K, MinMax<Self::GItem>>
+    where
+        Self::GItem: Clone,
+        K: Eq + Hash,
+        B: Ord,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> B;
+
+    /// Group by the key function given as parameter and sort the items of every group with `comparator`.
+    /// The sort is stable, so equal items keep the order of the iterator.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `comparator` -> function to sort the items of every group
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let sorted = [3, 1, 4, 1, 5, 9, 2, 6]
+    ///     .iter()
+    ///     .grouping_by_sorted_values(|&&x| x % 2, |a, b| a.cmp(b));
+    ///
+    /// assert_eq!(
+    ///     sorted,
+    ///     [(0, vec![&2, &4, &6]), (1, vec![&1, &1, &3, &5, &9])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<i32, Vec<&i32>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_sorted_values<K, F, C>(
+        self,
+        key: F,
+        comparator: C,
+    ) -> HashMap<K, Vec<Self::GItem>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        C: FnMut(&Self::GItem, &Self::GItem) -> std::cmp::Ordering;
+
+    /// Group by the key function given as parameter, removing the consecutive repeated items of every group.
+    /// Unlike `grouping_by_as_set`, only adjacent duplicates inside a group are removed,
+    /// so an item can appear several times if there are other items between its repetitions.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let deduped = [1, 1, 2, 1, 1].iter().grouping_by_dedup_consecutive(|_| ());
+    ///
+    /// assert_eq!(
+    ///     deduped,
+    ///     [((), vec![&1, &2, &1])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<(), Vec<&i32>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_dedup_consecutive<K, F>(self, key: F) -> HashMap<K, Vec<Self::GItem>>
+    where
+        Self::GItem: PartialEq,
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Return a map containing the geometric mean of the values of every group,
+    /// computed as `exp(mean(ln(value)))` in a single iteration.
+    /// The logarithm is only defined for positive values, so a group with any value
+    /// lower than or equal to zero has a mean of `f64::NAN`.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to average
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let means = [1.0, 4.0, -1.0].iter().grouping_by_geometric_mean(|&&x| x > 0.0, |&&x| x);
+    ///
+    /// assert!((means[&true] - 2.0).abs() < 1e-10);
+    /// assert!(means[&false].is_nan());
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_geometric_mean<K, F, G>(self, key: F, value: G) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64;
+
+    /// Return a map containing the harmonic mean of the values of every group,
+    /// computed as `count / sum(1 / value)` in a single iteration.
+    /// It is meant for positive values: a group with any zero value, either `0.0` or `-0.0`, has a mean of `0.0`,
+    /// and the mean of a group with negative values isn't meaningful.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to average
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// // Average speed of two trips of the same distance
+    /// let speeds = [("car", 60.0), ("car", 30.0), ("bike", 15.0)];
+    ///
+    /// let means = speeds.iter().grouping_by_harmonic_mean(|s| s.0, |s| s.1);
+    ///
+    /// assert!((means["car"] - 40.0).abs() < 1e-10);
+    /// assert!((means["bike"] - 15.0).abs() < 1e-10);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_harmonic_mean<K, F, G>(self, key: F, value: G) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64;
+
+    /// Count the occurrences of every value returned by `value` within each group.
+    /// The inner maps only contain the values seen in their group.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to count
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let words = ["apple", "avocado", "banana", "apricot"];
+    ///
+    /// let lengths = words.iter().grouping_by_value_counts(|word| word.chars().next(), |word| word.len());
+    ///
+    /// assert_eq!(lengths[&Some('a')][&7], 2);
+    /// assert_eq!(lengths[&Some('a')][&5], 1);
+    /// assert_eq!(lengths[&Some('b')][&6], 1);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_value_counts<K, V, F, G>(
+        self,
+        key: F,
+        value: G,
+    ) -> HashMap<K, HashMap<V, usize>>
+    where
+        K: Eq + Hash,
+        V: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V;
+
+    /// Return a map containing the sums of two values of every group at once, in a single iteration.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `values` -> function to get the pair of values to sum
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// // (product, units, revenue)
+    /// let sales = [("a", 2, 10.0), ("b", 1, 3.5), ("a", 3, 15.0)];
+    ///
+    /// let sums = sales.iter().grouping_by_summing_tuple(|s| s.0, |s| (s.1, s.2));
+    ///
+    /// assert_eq!(
+    ///     sums,
+    ///     [("a", (5, 25.0)), ("b", (1, 3.5))]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<&str, (i32, f64)>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_summing_tuple<K, V1, V2, F, G>(self, key: F, values: G) -> HashMap<K, (V1, V2)>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> (V1, V2),
+        V1: Default + std::ops::AddAssign,
+        V2: Default + std::ops::AddAssign;
+
+    /// Return the key of the group with the most elements and its number of elements,
+    /// or `None` if the iterator is empty. On ties, the key which reached the maximum
+    /// number of elements first in the iterator wins.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let largest = [1, 2, 2, 3, 3].iter().grouping_by_largest_group(|&&x| x);
+    ///
+    /// assert_eq!(largest, Some((2, 2)));
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_largest_group<K, F>(self, key: F) -> Option<(K, usize)>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K;
+
+    /// Group by the key function given as parameter, keeping only the groups which satisfy the predicate.
+    /// The predicate is called once per group, after the whole iterator has been grouped,
+    /// like a `HAVING` clause in SQL.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `predicate` -> function to decide if a complete group is kept
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let repeated = [1, 2, 2, 3, 3, 3]
+    ///     .iter()
+    ///     .grouping_by_filter_groups(|&&x| x, |_, group| group.len() >= 2);
+    ///
+    /// assert_eq!(
+    ///     repeated,
+    ///     [(2, vec![&2, &2]), (3, vec![&3, &3, &3])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<i32, Vec<&i32>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_filter_groups<K, F, P>(
+        self,
+        key: F,
+        predicate: P,
+    ) -> HashMap<K, Vec<Self::GItem>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        P: FnMut(&K, &Vec<Self::GItem>) -> bool;
+
+    /// Group by the key function given as parameter, keeping a uniform random sample of at most `k`
+    /// items per group. It uses reservoir sampling, so only `k` items per group are stored while iterating.
+    /// The groups with fewer than `k` items keep all of them.
+    ///
+    /// Only available with the `rand` feature, since the sample is drawn from the random number generator `rng`.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `k` -> maximum number of items of every group
+    ///
+    /// `rng` -> random number generator used to draw the sample
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use rand::SeedableRng;
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    ///
+    /// let sample = (0..100).grouping_by_sample(|x| x % 2, 3, &mut rng);
+    ///
+    /// assert_eq!(sample[&0].len(), 3);
+    /// assert!(sample[&1].iter().all(|x| x % 2 == 1));
+    /// ```
+    #[cfg(feature = "rand")]
+    fn grouping_by_sample<K, F, R>(
+        self,
+        key: F,
+        k: usize,
+        rng: &mut R,
+    ) -> HashMap<K, Vec<Self::GItem>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        R: rand::Rng + ?Sized;
+
+    /// Count the elements of the iterator given a function, adding the counts to an existing map
+    /// instead of creating a new one. The counts already in the map are preserved and incremented.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let mut counts = [1, 2].iter().counter(|&&x| x);
+    /// [2, 3].iter().counter_into_existing(&mut counts, |&&x| x);
+    ///
+    /// assert_eq!(counts, [(1, 1), (2, 2), (3, 1)].iter().cloned().collect());
+    /// ```
+    #[cfg(feature = "std")]
+    fn counter_into_existing<K, F>(self, map: &mut HashMap<K, usize>, key: F)
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K;
+
+    /// Rank the elements of every group by the value returned by `f`, the smallest one having rank 1.
+    /// It uses standard competition ranking: equal values share the same rank, and the next rank
+    /// skips as many positions as items tied, like `RANK()` in SQL (e.g. `1, 1, 3`).
+    /// The items of every group are sorted by rank, keeping the order of the iterator on ties.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `f` -> function to get the value by which the items are ranked
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let ranks = [10, 20, 10, 30].iter().grouping_by_rank(|_| (), |&&x| x);
+    ///
+    /// assert_eq!(ranks[&()], vec![(&10, 1), (&10, 1), (&20, 3), (&30, 4)]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_rank<K, B, F, G>(self, key: F, f: G) -> HashMap<K, Vec<(Self::GItem, usize)>>
+    where
+        K: Eq + Hash,
+        B: Ord,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> B;
+
+    /// Rank the elements of every group by the value returned by `f`, the smallest one having rank 1.
+    /// It uses dense ranking: equal values share the same rank, and the next rank is consecutive
+    /// without gaps, like `DENSE_RANK()` in SQL (e.g. `1, 1, 2`).
+    /// The items of every group are sorted by rank, keeping the order of the iterator on ties.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `f` -> function to get the value by which the items are ranked
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let ranks = [10, 20, 10, 30].iter().grouping_by_dense_rank(|_| (), |&&x| x);
+    ///
+    /// assert_eq!(ranks[&()], vec![(&10, 1), (&10, 1), (&20, 2), (&30, 3)]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_dense_rank<K, B, F, G>(
+        self,
+        key: F,
+        f: G,
+    ) -> HashMap<K, Vec<(Self::GItem, usize)>>
+    where
+        K: Eq + Hash,
+        B: Ord,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> B;
+
+    /// Scale the values of every group to `[0, 1]`, mapping the minimum of the group to `0.0`
+    /// and the maximum to `1.0`. The values keep the order of the iterator.
+    /// If all the values of a group are equal, they are all scaled to `0.0`.
+    ///
+    /// The minimum and maximum of a group are only known at the end, so its values are kept and then scaled in place.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to scale
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let scaled = [10.0, 30.0, 20.0, 5.0].iter().grouping_by_normalize(|&&x| x > 6.0, |&&x| x);
+    ///
+    /// assert_eq!(scaled[&true], vec![0.0, 1.0, 0.5]);
+    /// assert_eq!(scaled[&false], vec![0.0]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_normalize<K, F, G>(self, key: F, value: G) -> HashMap<K, Vec<f64>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64;
+
+    /// Standardize the values of every group, computing `(value - mean) / stddev` with the mean
+    /// and the population standard deviation of the group. The values keep the order of the iterator.
+    /// If the standard deviation of a group is zero, its values are all `0.0`.
+    ///
+    /// The values of every group are kept to compute its mean, and then read again for the standard deviation
+    /// and to standardize them in place.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to standardize
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let scores = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+    ///     .iter()
+    ///     .grouping_by_zscore(|_| (), |&&x| x);
+    ///
+    /// assert_eq!(scores[&()], vec![-1.5, -0.5, -0.5, -0.5, 0.0, 0.0, 1.0, 2.0]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_zscore<K, F, G>(self, key: F, value: G) -> HashMap<K, Vec<f64>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64;
+
+    /// Build a pivot table with the rows and the columns given by `row` and `col`.
+    /// Returns the sorted row labels, the sorted column labels and a dense matrix with a row
+    /// per row label and a column per column label, which is `None` for the missing cells.
+    /// When several items fall in the same cell, the value of the last one is kept.
+    ///
+    /// Params:
+    ///
+    /// `row` -> function to get the row label of every item
+    ///
+    /// `col` -> function to get the column label of every item
+    ///
+    /// `value` -> function to get the value of the cell of every item
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// // (year, quarter, sales)
+    /// let sales = [(2021, 1, 10), (2020, 2, 7), (2021, 2, 12), (2020, 1, 5), (2022, 2, 3)];
+    ///
+    /// let (years, quarters, table) = sales.iter().grouping_by_pivot(|s| s.0, |s| s.1, |s| s.2);
+    ///
+    /// assert_eq!(years, vec![2020, 2021, 2022]);
+    /// assert_eq!(quarters, vec![1, 2]);
+    /// assert_eq!(table, vec![vec![Some(5), Some(7)], vec![Some(10), Some(12)], vec![None, Some(3)]]);
+    /// ```
+    fn grouping_by_pivot<R, C, V, FR, FC, FV>(
+        self,
+        row: FR,
+        col: FC,
+        value: FV,
+    ) -> (Vec<R>, Vec<C>, Vec<Vec<Option<V>>>)
+    where
+        R: Ord,
+        C: Ord + Clone,
+        FR: FnMut(&Self::GItem) -> R,
+        FC: FnMut(&Self::GItem) -> C,
+        FV: FnMut(&Self::GItem) -> V;
+
+    /// Compute the Shannon entropy, in bits, of the distribution of the values returned by `value`
+    /// within each group. A group whose items all have the same value has an entropy of `0.0`.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values of the distribution
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let coins = [("fair", 'h'), ("fair", 't'), ("loaded", 'h'), ("loaded", 'h')];
+    ///
+    /// let entropies = coins.iter().grouping_by_entropy(|c| c.0, |c| c.1);
+    ///
+    /// assert_eq!(entropies["fair"], 1.0);
+    /// assert_eq!(entropies["loaded"], 0.0);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_entropy<K, V, F, G>(self, key: F, value: G) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        V: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V;
+
+    /// Return a map containing the running sums of the values of every group, in the order of the iterator.
+    /// The `i`-th element of a group is the sum of its first `i + 1` values.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to sum
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let running = [1, 2, 3, 4, 5].iter().grouping_by_cumulative(|&&x| x % 2, |&&x| x);
+    ///
+    /// assert_eq!(
+    ///     running,
+    ///     [(0, vec![2, 6]), (1, vec![1, 4, 9])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<i32, Vec<i32>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_cumulative<K, V, F, G>(self, key: F, value: G) -> HashMap<K, Vec<V>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+        V: Clone + std::ops::AddAssign;
+
+    /// Group the chars returned by `ch` into a `String` per group, in the order of the iterator.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `ch` -> function to get the char of every item
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let strings = "grouping".chars().grouping_by_collect_string(|c| "aeiou".contains(*c), |&c| c);
+    ///
+    /// assert_eq!(strings[&true], "oui");
+    /// assert_eq!(strings[&false], "grpng");
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_collect_string<K, F, G>(self, key: F, ch: G) -> HashMap<K, String>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> char;
+
+    /// Group by the key function given as parameter, keeping only the keys with exactly one item.
+    /// The keys which appear more than once are omitted.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let unique = [1, 2, 2, 3].iter().grouping_by_unique(|&&x| x);
+    ///
+    /// assert_eq!(
+    ///     unique,
+    ///     [(1, &1), (3, &3)].iter().cloned().collect::<HashMap<i32, &i32>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_unique<K, F>(self, key: F) -> HashMap<K, Self::GItem>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Group by the key function given as parameter, keeping only the groups with at least two items.
+    /// It is useful to find the repeated keys of a dataset.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let duplicates = [1, 2, 2, 3].iter().grouping_by_duplicates(|&&x| x);
+    ///
+    /// assert_eq!(
+    ///     duplicates,
+    ///     [(2, vec![&2, &2])].iter().cloned().collect::<HashMap<i32, Vec<&i32>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_duplicates<K, F>(self, key: F) -> HashMap<K, Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Group by the key function given as parameter, splitting every group into chunks of `size` items.
+    /// The last chunk of a group may be smaller. The order of the iterator is kept across and within chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0, just like `slice::chunks`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let chunks = [1, 2, 3, 4, 5, 6, 7].iter().grouping_by_chunks(|&&x| x % 2, 2);
+    ///
+    /// assert_eq!(chunks[&1], vec![vec![&1, &3], vec![&5, &7]]);
+    /// assert_eq!(chunks[&0], vec![vec![&2, &4], vec![&6]]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_chunks<K, F>(self, key: F, size: usize) -> HashMap<K, Vec<Vec<Self::GItem>>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Group the next `n` elements of the iterator by the key function given as parameter,
+    /// leaving the rest of the elements in the iterator, so it can keep being used afterwards.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let mut numbers = [1, 2, 3, 4, 5].iter();
+    ///
+    /// let first = numbers.grouping_by_take(2, |&&x| x % 2);
+    ///
+    /// assert_eq!(first, [(0, vec![&2]), (1, vec![&1])].iter().cloned().collect::<HashMap<i32, Vec<&i32>>>());
+    /// assert_eq!(numbers.next(), Some(&3));
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_take<K, F>(&mut self, n: usize, key: F) -> HashMap<K, Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Return a map containing the sum of the values of every group, just like `summing`,
+    /// but failing with the key of the first group whose sum overflows instead of wrapping.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to sum
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let sums = [100u8, 50, 100, 200].iter().grouping_by_checked_summing(|&&x| x > 99, |&&x| x);
+    ///
+    /// assert_eq!(sums, Err(true));
+    ///
+    /// let sums = [100u8, 50, 100].iter().grouping_by_checked_summing(|&&x| x > 99, |&&x| x);
+    ///
+    /// assert_eq!(sums, Ok([(true, 200), (false, 50)].iter().cloned().collect::<HashMap<bool, u8>>()));
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_checked_summing<K, V, F, G>(self, key: F, value: G) -> Result<HashMap<K, V>, K>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+        V: Default + CheckedAdd;
+
+    /// Return a map containing the sum of the values of every group, just like `summing`,
+    /// but clamping the sums to the bounds of the type instead of wrapping on overflow.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to sum
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let sums = [100u8, 50, 100, 200].iter().grouping_by_saturating_summing(|&&x| x > 99, |&&x| x);
+    ///
+    /// assert_eq!(sums, [(true, 255), (false, 50)].iter().cloned().collect::<HashMap<bool, u8>>());
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_saturating_summing<K, V, F, G>(self, key: F, value: G) -> HashMap<K, V>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+        V: Default + SaturatingAdd;
+
+    /// Return a map containing the sum and the number of the values of every group.
+    /// Unlike an average, these pairs can be merged, adding their sums and counts,
+    /// before dividing them, e.g. to combine partial results computed separately.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to sum
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let pairs = [1, 2, 3, 4, 5].iter().grouping_by_averaging_pairs(|&&x| x % 2, |&&x| x);
+    ///
+    /// assert_eq!(
+    ///     pairs,
+    ///     [(0, (6, 2)), (1, (9, 3))].iter().cloned().collect::<HashMap<i32, (i32, usize)>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_averaging_pairs<K, V, F, G>(self, key: F, value: G) -> HashMap<K, (V, usize)>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+        V: Default + std::ops::AddAssign;
+
+    /// Group by a key function returning small non-negative integers, storing the groups
+    /// in a `Vec` indexed by key instead of a `HashMap`. The resulting `Vec` has `max_key + 1` groups,
+    /// which are empty for the keys without items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key of any item is greater than `max_key`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let groups = [1, 2, 3, 4, 6].iter().grouping_by_bitmap(|&&x| x % 4, 3);
+    ///
+    /// assert_eq!(groups, vec![vec![&4], vec![&1], vec![&2, &6], vec![&3]]);
+    /// ```
+    fn grouping_by_bitmap<F>(self, key: F, max_key: usize) -> Vec<Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> usize;
+
+    /// Group by the key function given as parameter, just like `grouping_by`, but storing every group
+    /// in a `SmallVec`, so the groups with up to `N` items don't allocate on the heap.
+    ///
+    /// Only available with the `smallvec` feature.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let numbers_grouped = [-1i8, -2, 1, 2, 1].iter().grouping_by_smallvec::<2, _, _>(|number| number.abs());
+    ///
+    /// assert_eq!(numbers_grouped[&2].as_slice(), &[&-2, &2]);
+    /// assert!(!numbers_grouped[&2].spilled());
+    /// assert_eq!(numbers_grouped[&1].as_slice(), &[&-1, &1, &1]);
+    /// assert!(numbers_grouped[&1].spilled());
+    /// ```
+    #[cfg(feature = "smallvec")]
+    fn grouping_by_smallvec<const N: usize, K, F>(
+        self,
+        key: F,
+    ) -> HashMap<K, smallvec::SmallVec<[Self::GItem; N]>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Build a contingency table counting the elements of every pair of row and column,
+    /// with the totals of every row, every column and the whole table.
+    ///
+    /// Params:
+    ///
+    /// `row` -> function to get the row of every item
+    ///
+    /// `col` -> function to get the column of every item
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// // (gender, smoker)
+    /// let people = [("f", true), ("m", false), ("f", false), ("f", true)];
+    ///
+    /// let crosstab = people.iter().grouping_by_crosstab(|p| p.0, |p| p.1);
+    ///
+    /// assert_eq!(crosstab.count(&"f", &true), 2);
+    /// assert_eq!(crosstab.count(&"m", &true), 0);
+    /// assert_eq!(crosstab.row_total(&"f"), 3);
+    /// assert_eq!(crosstab.col_total(&false), 2);
+    /// assert_eq!(crosstab.grand_total(), 4);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_crosstab<R, C, FR, FC>(self, row: FR, col: FC) -> Crosstab<R, C>
+    where
+        R: Eq + Hash,
+        C: Eq + Hash,
+        FR: FnMut(&Self::GItem) -> R,
+        FC: FnMut(&Self::GItem) -> C;
+
+    /// Group by the key function given as parameter, keeping only the first `n` items of every group
+    /// in the order of the iterator. The rest of the items are discarded without being stored.
+    /// With `n == 0` every key is still present in the resulting map, with an empty group.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let first = [1, 2, 3, 4, 5, 7].iter().grouping_by_first_n(|&&x| x % 2, 2);
+    ///
+    /// assert_eq!(
+    ///     first,
+    ///     [(0, vec![&2, &4]), (1, vec![&1, &3])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<i32, Vec<&i32>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_first_n<K, F>(self, key: F, n: usize) -> HashMap<K, Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Group by the key function given as parameter, keeping only the last `n` items of every group.
+    /// Every group is stored in a ring buffer, so only `n` items per group are kept while iterating.
+    /// The items of every group keep the order of the iterator, from the oldest to the most recent.
+    /// With `n == 0` every key is still present in the resulting map, with an empty group.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let last = [1, 2, 3, 4, 5, 7].iter().grouping_by_last_n(|&&x| x % 2, 2);
+    ///
+    /// assert_eq!(
+    ///     last,
+    ///     [(0, vec![&2, &4]), (1, vec![&5, &7])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<i32, Vec<&i32>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_last_n<K, F>(self, key: F, n: usize) -> HashMap<K, Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Group by the key function given as parameter, returning for every group a `HashSet`
+    /// with its distinct items and the total number of items, including the repeated ones.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let events = [("ana", "login"), ("ana", "login"), ("ana", "buy"), ("bob", "login")];
+    ///
+    /// let grouped = events.iter().grouping_by_set_with_count(|event| event.0);
+    ///
+    /// assert_eq!(grouped["ana"].0.len(), 2);
+    /// assert_eq!(grouped["ana"].1, 3);
+    /// assert_eq!(grouped["bob"].1, 1);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_set_with_count<K, F>(self, key: F) -> HashMap<K, (HashSet<Self::GItem>, usize)>
+    where
+        Self::GItem: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Sum the values of every group and return the key with the largest sum and its sum,
+    /// or `None` if the iterator is empty. On ties, the key whose sum reached the maximum
+    /// first in the iterator wins.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the groups
+    ///
+    /// `value` -> function to get the values to sum
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// // (seller, amount)
+    /// let sales = [("ana", 10), ("bob", 15), ("ana", 10), ("carl", 20)];
+    ///
+    /// let best = sales.iter().grouping_by_argmax_sum(|sale| sale.0, |sale| sale.1);
+    ///
+    /// assert_eq!(best, Some(("ana", 20)));
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_argmax_sum<K, V, F, G>(self, key: F, value: G) -> Option<(K, V)>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+        V: Default + std::ops::AddAssign + PartialOrd;
+
+    /// Divide the values of every group by the maximum of the group, so the maximum becomes `1.0`.
+    /// The values keep the order of the iterator.
+    /// The ratios are plain float divisions: if the maximum of a group is zero, they are infinite
+    /// (or `NAN` for the zeros), and if it is negative, the signs are flipped and the ratios are at least `1.0`.
+    ///
+    /// The maximum of a group is only known at the end, so its values are kept and then divided in place.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to divide
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let ratios = [5.0, 10.0, 2.5].iter().grouping_by_ratio_to_max(|_| (), |&&x| x);
+    ///
+    /// assert_eq!(ratios[&()], vec![0.5, 1.0, 0.25]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_ratio_to_max<K, F, G>(self, key: F, value: G) -> HashMap<K, Vec<f64>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64;
+
+    /// Group by the key function given as parameter, appending the items transformed by `mapper`
+    /// into an existing map instead of creating a new one. The existing groups are appended to,
+    /// and the missing ones are created.
+    ///
+    /// Params:
+    ///
+    /// `map` -> map where the transformed items are appended
+    ///
+    /// `key` -> function to create the keys of the map
+    ///
+    /// `mapper` -> function to transform every item before appending it
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let mut lengths = HashMap::new();
+    /// ["a", "bb"].iter().grouping_by_mapping_into_existing(&mut lengths, |s| s.len() > 1, |s| s.len());
+    /// ["ccc"].iter().grouping_by_mapping_into_existing(&mut lengths, |s| s.len() > 1, |s| s.len());
+    ///
+    /// assert_eq!(lengths, [(false, vec![1]), (true, vec![2, 3])].iter().cloned().collect());
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_mapping_into_existing<K, V, F, G>(
+        self,
+        map: &mut HashMap<K, Vec<V>>,
+        key: F,
+        mapper: G,
+    ) where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(Self::GItem) -> V;
+
+    /// Reduce the items of every group with the function given as parameter, just like Java
+    /// `Collectors.reducing` without identity. Every group is the left fold of its items
+    /// in the order of the iterator, and the groups with one item keep it as is.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `op` -> function to combine the accumulated item of a group with the next one
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let longest = vec!["a", "ccc", "bb", "dd"]
+    ///     .into_iter()
+    ///     .grouping_by_reduce(|s| s.len() > 1, |a, b| if b.len() > a.len() { b } else { a });
+    ///
+    /// assert_eq!(longest, [(false, "a"), (true, "ccc")].iter().cloned().collect::<HashMap<bool, &str>>());
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_reduce<K, F, R>(self, key: F, op: R) -> HashMap<K, Self::GItem>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        R: FnMut(Self::GItem, Self::GItem) -> Self::GItem;
+
+    /// Compute all the elements of every group which are tied for the maximum,
+    /// compared by the value returned by `f`, just like `grouping_by_all_max`.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `f` -> function to get the value by which the items are compared
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let words = ["bb", "a", "cc", "ddd"];
+    ///
+    /// let longest = words.iter().grouping_by_max_all_by_key(|word| word.len() < 3, |word| word.len());
+    ///
+    /// assert_eq!(longest[&true], vec![&"bb", &"cc"]);
+    /// assert_eq!(longest[&false], vec![&"ddd"]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_max_all_by_key<K, B, F, G>(self, key: F, f: G) -> HashMap<K, Vec<Self::GItem>>
+    where
+        K: Eq + Hash,
+        B: Ord,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> B;
+
+    /// Compute the percentage of the total number of elements that every group represents,
+    /// rounded to the nearest whole percent (halves are rounded away from zero).
+    /// Because of the rounding, the percentages may not sum exactly 100.
+    /// An empty iterator returns an empty map.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let percentages = [1, 1, 2].iter().counter_percentages(|&&x| x);
+    ///
+    /// assert_eq!(
+    ///    percentages,
+    ///    [(1, 67), (2, 33)]
+    ///        .iter()
+    ///        .cloned()
+    ///        .collect::<HashMap<i8, u8>>()
+    /// )
+    /// ```
+    #[cfg(feature = "std")]
+    fn counter_percentages<K, F>(self, key: F) -> HashMap<K, u8>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K;
+
+    /// Join the values of every group into a `String`, just like Java
+    /// `Collectors.joining(delimiter, prefix, suffix)`. Every group becomes
+    /// `prefix + v1 + sep + v2 + ... + suffix`, with the values in the order of the iterator.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to join
+    ///
+    /// `prefix` -> string at the start of every group
+    ///
+    /// `sep` -> string between every two values
+    ///
+    /// `suffix` -> string at the end of every group
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let joined = [1, 2, 3, 4].iter().grouping_by_join_with(|&&x| x % 2, |&&x| x, "<", "|", ">");
+    ///
+    /// assert_eq!(joined[&0], "<2|4>");
+    /// assert_eq!(joined[&1], "<1|3>");
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_join_with<K, V, F, G>(
+        self,
+        key: F,
+        value: G,
+        prefix: &str,
+        sep: &str,
+        suffix: &str,
+    ) -> HashMap<K, String>
+    where
+        K: Eq + Hash,
+        V: std::fmt::Display,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V;
+
+    /// Count, for every group, the items which satisfy each one of the labeled predicates given as parameter.
+    /// The inner maps contain every label, with a count of 0 if no item of the group satisfies its predicate.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `predicates` -> labels and predicates to count
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let counts = [1, 2, 3, 4, 5, 6].iter().grouping_by_count_where(
+    ///     |&&x| x > 3,
+    ///     &[
+    ///         ("even", Box::new(|&&x: &&i32| x % 2 == 0)),
+    ///         ("multiple of 3", Box::new(|&&x: &&i32| x % 3 == 0)),
+    ///     ],
+    /// );
+    ///
+    /// assert_eq!(counts[&false]["even"], 1);
+    /// assert_eq!(counts[&false]["multiple of 3"], 1);
+    /// assert_eq!(counts[&true]["even"], 2);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_count_where<K, F>(
+        self,
+        key: F,
+        predicates: &[LabeledPredicate<Self::GItem>],
+    ) -> HashMap<K, HashMap<String, usize>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K;
+
+    /// Group by the key function given as parameter, returning the groups sorted by their number
+    /// of items in descending order. The groups with the same size are sorted by the position
+    /// of their first item in the iterator.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let ranked = [3, 1, 2, 2, 1].iter().grouping_by_ranked_by_size(|&&x| x);
+    ///
+    /// assert_eq!(ranked, vec![(1, vec![&1, &1]), (2, vec![&2, &2]), (3, vec![&3])]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_ranked_by_size<K, F>(self, key: F) -> Vec<(K, Vec<Self::GItem>)>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Group by the key function given as parameter.
+    /// The keys are the different values that the function can return,
+    /// and the values are a `BTreeSet` with the items of the iterator which has the key as property,
+    /// so the distinct items of every group are iterated in ascending order
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let numbers_grouped = [3, 1, 2, 3, 1].iter().grouping_by_as_btreeset(|_| ());
+    ///
+    /// assert_eq!(
+    ///     numbers_grouped[&()].iter().collect::<Vec<_>>(),
+    ///     vec![&&1, &&2, &&3]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_as_btreeset<K, F>(self, key: F) -> HashMap<K, BTreeSet<Self::GItem>>
+    where
+        Self::GItem: Ord,
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Group by the key function given as parameter.
+    /// The keys are the different values that the function can return,
+    /// and the values are a `VecDeque` with the items of the iterator which has the key as property,
+    /// pushed to the back in the order of the iterator
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let mut queues = [1, 2, 3, 4, 5].iter().grouping_by_as_vecdeque(|&&x| x % 2);
+    ///
+    /// let odds = queues.get_mut(&1).unwrap();
+    /// assert_eq!(odds.pop_front(), Some(&1));
+    /// assert_eq!(odds.back(), Some(&&5));
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_as_vecdeque<K, F>(self, key: F) -> HashMap<K, VecDeque<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Return a map containing the average of the values of the elements grouped by two nested key functions,
+    /// computed in a single iteration. The inner maps only contain the inner keys seen under their outer key.
+    ///
+    /// Params:
+    ///
+    /// `outer` -> function to create the keys of the resulting map
+    ///
+    /// `inner` -> function to create the keys of the nested maps
+    ///
+    /// `value` -> function to get the values to average
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// // (region, product, price)
+    /// let sales = [("north", "a", 3.0), ("north", "a", 2.0), ("north", "b", 1.0), ("south", "a", 4.0)];
+    ///
+    /// let means = sales.iter().grouping_by_then_averaging(|s| s.0, |s| s.1, |s| s.2);
+    ///
+    /// assert_eq!(means["north"]["a"], 2.5);
+    /// assert_eq!(means["north"]["b"], 1.0);
+    /// assert_eq!(means["south"]["a"], 4.0);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_then_averaging<K1, K2, F, G, H>(
+        self,
+        outer: F,
+        inner: G,
+        value: H,
+    ) -> HashMap<K1, HashMap<K2, f64>>
+    where
+        K1: Eq + Hash,
+        K2: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K1,
+        G: FnMut(&Self::GItem) -> K2,
+        H: FnMut(&Self::GItem) -> f64;
+
+    /// Group by the key function given as parameter, just like `grouping_by`,
+    /// and then apply `finisher` to every completed group, just like Java `Collectors.collectingAndThen`.
+    /// The finisher runs exactly once per key, after the whole iterator has been grouped.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `finisher` -> function to transform every group into the value of the resulting map
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let words = ["apple", "avocado", "banana", "blueberry", "cherry"];
+    ///
+    /// let lengths = words.iter().grouping_by_with_finisher(|w| w.as_bytes()[0], |group| group.len());
+    ///
+    /// assert_eq!(lengths[&b'a'], 2);
+    /// assert_eq!(lengths[&b'b'], 2);
+    /// assert_eq!(lengths[&b'c'], 1);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_with_finisher<K, R, F, Fin>(self, key: F, finisher: Fin) -> HashMap<K, R>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        Fin: FnMut(Vec<Self::GItem>) -> R;
+
+    /// Lazily group an iterator whose items with the same key are contiguous, like sorted input.
+    /// Every time the key changes the finished run is yielded with its key, so only the current
+    /// group is kept in memory instead of building the whole map.
+    ///
+    /// It assumes the keys are grouped contiguously: if a key shows up again after a different one,
+    /// it starts a new run. It only needs `alloc`, so it is available without the `std` feature.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let mut runs = [1, 2, 11, 15, 17, 23].iter().grouping_by_sorted_runs(|&&x| x / 10);
+    ///
+    /// assert_eq!(runs.next(), Some((0, vec![&1, &2])));
+    /// assert_eq!(runs.next(), Some((1, vec![&11, &15, &17])));
+    /// assert_eq!(runs.next(), Some((2, vec![&23])));
+    /// assert_eq!(runs.next(), None);
+    /// ```
+    fn grouping_by_sorted_runs<K, F>(self, key: F) -> SortedRuns<Self, K, F>
+    where
+        Self: Iterator + Sized,
+        K: PartialEq,
+        F: FnMut(&<Self as Iterator>::Item) -> K;
+
+    /// Return the key of the group with the fewest elements and its number of elements,
+    /// or `None` if the iterator is empty. On ties, the key which appeared first in the iterator wins.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let smallest = [1, 2, 2, 3, 1, 1].iter().grouping_by_smallest_group(|&&x| x);
+    ///
+    /// assert_eq!(smallest, Some((3, 1)));
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_smallest_group<K, F>(self, key: F) -> Option<(K, usize)>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K;
+
+    /// Return a map containing the running maximum of the values of every group, in the order of the iterator.
+    /// The `i`-th element of a group is the greatest of its first `i + 1` values, its high-water mark.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `f` -> function to get the values to compare
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let running = [3, 1, 4, 1, 5, 9, 2, 6].iter().grouping_by_running_max(|&&x| x % 2, |&&x| x);
+    ///
+    /// assert_eq!(
+    ///     running,
+    ///     [(0, vec![4, 4, 6]), (1, vec![3, 3, 3, 5, 9])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<i32, Vec<i32>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_running_max<K, B, F, G>(self, key: F, f: G) -> HashMap<K, Vec<B>>
+    where
+        K: Eq + Hash,
+        B: Ord + Clone,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> B;
+
+    /// Return a map containing the successive differences of the values of every group, in the order of the iterator.
+    /// The `i`-th element of a group is its `i + 1`-th value minus its `i`-th one,
+    /// so a group with a single item has no differences.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to subtract
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// // (sensor, reading)
+    /// let readings = [("a", 10), ("b", 3), ("a", 12), ("a", 11)];
+    ///
+    /// let diffs = readings.iter().grouping_by_diff(|r| r.0, |r| r.1);
+    ///
+    /// assert_eq!(
+    ///     diffs,
+    ///     [("a", vec![2, -1]), ("b", vec![])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<&str, Vec<i32>>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_diff<K, V, F, G>(self, key: F, value: G) -> HashMap<K, Vec<V>>
+    where
+        K: Eq + Hash,
+        V: std::ops::Sub<Output = V> + Clone,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V;
+
+    /// Group by the key function given as parameter and then collect every group with a fallible `collector`,
+    /// like `grouping_by_with_finisher` but stopping at the first group whose collector fails and returning its error.
+    /// The whole iterator is grouped first, so every group is fully buffered in memory before any of them is collected,
+    /// and then the groups are collected in the order their keys first appear,
+    /// so the error returned is the one of the earliest failing group.
+    /// Use `grouping_by_try_aggregate` with a `TryAccumulator` to aggregate the items as they come instead.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `collector` -> function to collect every group into the value of the resulting map
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// // (message id, payload chunk)
+    /// let chunks = [(1, "4"), (2, "x"), (1, "2")];
+    ///
+    /// let parsed = chunks.iter().grouping_by_collect_result(|c| c.0, |group| {
+    ///     group.iter().map(|c| c.1).collect::<String>().parse::<u32>()
+    /// });
+    ///
+    /// assert!(parsed.is_err());
+    ///
+    /// let parsed = chunks[..1].iter().grouping_by_collect_result(|c| c.0, |group| {
+    ///     group.iter().map(|c| c.1).collect::<String>().parse::<u32>()
+    /// });
+    ///
+    /// assert_eq!(parsed, Ok([(1, 4)].iter().cloned().collect::<HashMap<i32, u32>>()));
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_collect_result<K, A, E, F, C>(
+        self,
+        key: F,
+        collector: C,
+    ) -> Result<HashMap<K, A>, E>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        C: FnMut(Vec<Self::GItem>) -> Result<A, E>;
+
+    /// Group by the key function given as parameter, feeding the items of every group to a `TryAccumulator`
+    /// which starts from its `Default` value, like `grouping_by_aggregate` but stopping at the first item
+    /// which the accumulator of its group rejects and returning its error.
+    /// The items are aggregated as they come, so the groups are not buffered.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::{GroupingBy, TryAccumulator};
+    /// #[derive(Default)]
+    /// struct Digits(u32);
+    ///
+    /// impl TryAccumulator<char> for Digits {
+    ///     type Error = char;
+    ///
+    ///     fn try_accept(&mut self, c: &char) -> Result<(), char> {
+    ///         self.0 = self.0 * 10 + c.to_digit(10).ok_or(*c)?;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let parsed = "4a2".chars().grouping_by_try_aggregate::<_, Digits, _>(|c| c.is_numeric());
+    ///
+    /// assert_eq!(parsed.err(), Some('a'));
+    ///
+    /// let parsed = "42".chars().grouping_by_try_aggregate::<_, Digits, _>(|_| 0).unwrap();
+    ///
+    /// assert_eq!(parsed[&0].0, 42);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_try_aggregate<K, A, F>(self, key: F) -> Result<HashMap<K, A>, A::Error>
+    where
+        K: Eq + Hash,
+        A: TryAccumulator<Self::GItem> + Default,
+        F: FnMut(&Self::GItem) -> K;
+
+    /// Group by the key function given as parameter, splitting every group into overlapping windows of `size` items,
+    /// just like `slice::windows`. A group with fewer than `size` items has no windows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0, just like `slice::windows`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let windows = [1, 2, 3, 4, 5, 6, 7].iter().grouping_by_windows(|&&x| x % 2, 3);
+    ///
+    /// assert_eq!(windows[&1], vec![vec![&1, &3, &5], vec![&3, &5, &7]]);
+    /// assert_eq!(windows[&0], vec![vec![&2, &4, &6]]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_windows<K, F>(self, key: F, size: usize) -> HashMap<K, Vec<Vec<Self::GItem>>>
+    where
+        Self::GItem: Clone,
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Return a map containing all the most frequent values returned by `value` within each group.
+    /// When several values tie for the highest frequency all of them are returned,
+    /// ordered by their first appearance in the iterator.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values whose modes are computed
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let words = ["apple", "avocado", "banana", "apricot", "ant"];
+    ///
+    /// let modes = words.iter().grouping_by_mode_all(|word| word.chars().next(), |word| word.len());
+    ///
+    /// assert_eq!(modes[&Some('a')], vec![7]);
+    /// assert_eq!(modes[&Some('b')], vec![6]);
+    ///
+    /// let modes = [5, 3, 3, 5, 1].iter().grouping_by_mode_all(|_| (), |&&x| x);
+    ///
+    /// assert_eq!(modes[&()], vec![5, 3]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_mode_all<K, V, F, G>(self, key: F, value: G) -> HashMap<K, Vec<V>>
+    where
+        K: Eq + Hash,
+        V: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V;
+
+    /// Group by the key function given as parameter, feeding the items of every group to an `Accumulator`
+    /// which starts from its `Default` value. Implement `Accumulator` once to reuse any aggregation,
+    /// or use the provided `CountAcc` and `SumAcc`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::{CountAcc, GroupingBy, SumAcc};
+    /// let counts = [1, 2, 3, 4, 5].iter().grouping_by_aggregate::<_, CountAcc, _>(|&&x| x % 2);
+    ///
+    /// assert_eq!(counts[&1].count, 3);
+    ///
+    /// let sums = [1, 2, 3, 4, 5].iter().grouping_by_aggregate::<_, SumAcc<i32>, _>(|&&x| x % 2);
+    ///
+    /// assert_eq!(sums[&0].sum, 6);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_aggregate<K, A, F>(self, key: F) -> HashMap<K, A>
+    where
+        K: Eq + Hash,
+        A: Accumulator<Self::GItem> + Default,
+        F: FnMut(&Self::GItem) -> K;
+
+    /// Return a map containing the first item of every group which satisfies the predicate.
+    /// The groups where no item satisfies it are not present in the resulting map.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let firsts = [1, 2, 3, 4, 5, 6].iter().grouping_by_first_where(|&&x| x % 3, |&&x| x > 2);
+    ///
+    /// assert_eq!(
+    ///     firsts,
+    ///     [(0, &3), (1, &4), (2, &5)].iter().cloned().collect::<HashMap<i32, &i32>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_first_where<K, F, P>(self, key: F, predicate: P) -> HashMap<K, Self::GItem>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        P: FnMut(&Self::GItem) -> bool;
+
+    /// Return a map telling whether any item of every group satisfies the predicate.
+    /// Every group is present in the resulting map, and once one of its items satisfies the predicate
+    /// it isn't called again for the rest of the group.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let any_big = [1, 2, 3, 14, 5].iter().grouping_by_any(|&&x| x % 2, |&&x| x > 10);
+    ///
+    /// assert!(any_big[&0]);
+    /// assert!(!any_big[&1]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_any<K, F, P>(self, key: F, predicate: P) -> HashMap<K, bool>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        P: FnMut(&Self::GItem) -> bool;
+
+    /// Return a map telling whether all the items of every group satisfy the predicate.
+    /// Every group is present in the resulting map, and once one of its items doesn't satisfy the predicate
+    /// it isn't called again for the rest of the group.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let all_small = [1, 2, 3, 14, 5].iter().grouping_by_all(|&&x| x % 2, |&&x| x < 10);
+    ///
+    /// assert!(!all_small[&0]);
+    /// assert!(all_small[&1]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_all<K, F, P>(self, key: F, predicate: P) -> HashMap<K, bool>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        P: FnMut(&Self::GItem) -> bool;
+
+    /// Return a map containing the sum of the values of every group, just like `summing`,
+    /// but using Kahan compensated summation. Adding many `f64`s naively loses the low-order bits
+    /// of every small value added to a big sum, while the compensation keeps track of them,
+    /// so the result is much more accurate on large or widely-scaled inputs.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to sum
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let values = [1.0, 1e-16, 1e-16, 1e-16, 1e-16];
+    ///
+    /// let sums = values.iter().grouping_by_summing_kahan(|_| (), |&&x| x);
+    ///
+    /// assert_eq!(values.iter().sum::<f64>(), 1.0);
+    /// assert_eq!(sums[&()], 1.0000000000000004);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_summing_kahan<K, F, G>(self, key: F, value: G) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64;
+
+    /// Return a map containing the minimum, the maximum and the range, their difference,
+    /// of the values returned by `f` for every group, computed in a single iteration.
+    /// The range of a group with a single value is that value minus itself.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let spans = [3, 8, 5, 4, 1].iter().grouping_by_span(|&&x| x % 2, |&&x| x);
+    ///
+    /// assert_eq!(
+    ///     spans,
+    ///     [(0, (4, 8, 4)), (1, (1, 5, 4))]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<i32, (i32, i32, i32)>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_span<K, B, F, G>(self, key: F, f: G) -> HashMap<K, (B, B, B)>
+    where
+        K: Eq + Hash,
+        B: Ord + std::ops::Sub<Output = B> + Clone,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> B;
+
+    /// Return a map containing the number of items of every group which satisfy the predicate.
+    /// Unlike filtering before counting, the groups without any matching item are kept with a count of `0`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let nonzero = [0, 3, 0, 4, 5, 0].iter().grouping_by_count_matching(|&&x| x < 4, |&&x| x != 0);
+    ///
+    /// assert_eq!(
+    ///     nonzero,
+    ///     [(true, 1), (false, 2)].iter().cloned().collect::<HashMap<bool, usize>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_count_matching<K, F, P>(self, key: F, predicate: P) -> HashMap<K, usize>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        P: FnMut(&Self::GItem) -> bool;
+
+    /// Count the elements of the iterator given a function, returning the keys sorted by descending count,
+    /// each one with its count and the cumulative count of all the keys up to and including it,
+    /// which makes Pareto analysis straightforward. Keys with the same count keep their order of first appearance.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let counts = ["b", "a", "b", "c", "a", "b"].iter().counter_cumulative(|&&x| x);
+    ///
+    /// assert_eq!(counts, vec![("b", 3, 3), ("a", 2, 5), ("c", 1, 6)]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn counter_cumulative<K, F>(self, key: F) -> Vec<(K, usize, usize)>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K;
+
+    /// Return a map containing the maximum item of every group given by two nested key functions,
+    /// just like `grouping_by_max` but with two levels. The inner maps only contain the inner keys
+    /// seen under their outer key, and on ties the first item is kept.
+    ///
+    /// Params:
+    ///
+    /// `outer` -> function to create the keys of the resulting map
+    ///
+    /// `inner` -> function to create the keys of the nested maps
+    ///
+    /// `comparator` -> function to compare the items of every group
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// // (region, product, price)
+    /// let sales = [("north", "a", 3), ("north", "a", 5), ("north", "b", 1), ("south", "a", 4)];
+    ///
+    /// let maxima = sales.iter().grouping_by_then_max(|s| s.0, |s| s.1, |s1, s2| s1.2.cmp(&s2.2));
+    ///
+    /// assert_eq!(maxima["north"]["a"], &("north", "a", 5));
+    /// assert_eq!(maxima["north"]["b"], &("north", "b", 1));
+    /// assert_eq!(maxima["south"]["a"], &("south", "a", 4));
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_then_max<K1, K2, F, G, C>(
+        self,
+        outer: F,
+        inner: G,
+        comparator: C,
+    ) -> HashMap<K1, HashMap<K2, Self::GItem>>
+    where
+        K1: Eq + Hash,
+        K2: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K1,
+        G: FnMut(&Self::GItem) -> K2,
+        C: FnMut(&Self::GItem, &Self::GItem) -> std::cmp::Ordering;
+
+    /// Group by the key function given as parameter, keeping only the `k` groups with the most items.
+    /// On ties at the boundary, the groups whose first item appeared earlier in the iterator are kept.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let top = [1, 2, 2, 3, 3, 3, 4].iter().grouping_by_top_k_groups(|&&x| x, 2);
+    ///
+    /// assert_eq!(top.len(), 2);
+    /// assert_eq!(top[&3], vec![&3, &3, &3]);
+    /// assert_eq!(top[&2], vec![&2, &2]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_top_k_groups<K, F>(self, key: F, k: usize) -> HashMap<K, Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Group by the key function given as parameter, keeping the `k` groups with the most items
+    /// and merging the items of all the other groups into a single group with the key `other`,
+    /// like the "top categories + other" of a chart. The smaller groups are chosen just like
+    /// in `grouping_by_top_k_groups`, and their items are appended to the `other` group from the largest one
+    /// to the smallest one. If `other` is also the key of a kept group, the items are appended to that group.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let fruits = ["apple", "pear", "apple", "kiwi", "apple", "pear", "fig"];
+    ///
+    /// let groups = fruits.iter().grouping_by_with_other(|&&fruit| fruit, 2, "other");
+    ///
+    /// assert_eq!(groups.len(), 3);
+    /// assert_eq!(groups["apple"].len(), 3);
+    /// assert_eq!(groups["pear"].len(), 2);
+    /// assert_eq!(groups["other"], vec![&"kiwi", &"fig"]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_with_other<K, F>(
+        self,
+        key: F,
+        k: usize,
+        other: K,
+    ) -> HashMap<K, Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Compute the interquartile range, `Q3 - Q1`, of the values of every group.
+    /// The quartiles are computed just like in `grouping_by_quantiles`, by linear interpolation
+    /// between the two closest order statistics, so the values of every group are collected and sorted.
+    /// It's a measure of dispersion less sensitive to outliers than the standard deviation.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values of the quartiles
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let iqrs = [1.0, 3.0, 2.0, 4.0].iter().grouping_by_iqr(|_| (), |&&x| x);
+    ///
+    /// // Q1 = 1.75 and Q3 = 3.25
+    /// assert_eq!(iqrs[&()], 1.5);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_iqr<K, F, G>(self, key: F, value: G) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64;
+
+    /// Return a map containing the outliers of every group, the items whose value is outside Tukey's fences:
+    /// below `Q1 - 1.5 * IQR` or above `Q3 + 1.5 * IQR`. The quartiles are computed just like
+    /// in `grouping_by_iqr`. The groups without outliers are not present in the resulting map,
+    /// and the outliers of every group keep the order of the iterator.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values of the items
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let latencies = [12.0, 11.0, 250.0, 13.0, 12.0, 10.0];
+    ///
+    /// let outliers = latencies.iter().grouping_by_outliers(|_| (), |&&x| x);
+    ///
+    /// assert_eq!(outliers[&()], vec![&250.0]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_outliers<K, F, G>(self, key: F, value: G) -> HashMap<K, Vec<Self::GItem>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64;
+
+    /// Count the elements of the iterator given a function, scaling the counts so they add up to `target`,
+    /// like `grouping_by_fractions` but for any total: `100.0` gives percentages and `1.0` probabilities.
+    /// An empty iterator returns an empty map.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let percentages = [1, 2, 2, 3].iter().counter_normalized(|&&x| x, 100.0);
+    ///
+    /// assert_eq!(percentages[&1], 25.0);
+    /// assert_eq!(percentages[&2], 50.0);
+    /// assert_eq!(percentages[&3], 25.0);
+    /// ```
+    #[cfg(feature = "std")]
+    fn counter_normalized<K, F>(self, key: F, target: f64) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K;
+
+    /// Return a map containing a `NumericSummary` of the values of every group: their count, minimum, maximum,
+    /// sum, mean and sum of squared differences from the mean, from which the variance and
+    /// the standard deviation are derived. Everything is computed in a single iteration without collecting the values.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to summarize
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let summaries = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+    ///     .iter()
+    ///     .grouping_by_numeric_summary(|_| (), |&&x| x);
+    ///
+    /// let summary = &summaries[&()];
+    /// assert_eq!(summary.count, 8);
+    /// assert_eq!((summary.min, summary.max), (2.0, 9.0));
+    /// assert_eq!(summary.mean, 5.0);
+    /// assert_eq!(summary.stddev(), 2.0);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_numeric_summary<K, F, G>(self, key: F, value: G) -> HashMap<K, NumericSummary>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64;
+
+    /// Group by the key function given as parameter, just like `grouping_by`, but returning an iterator
+    /// which yields every key with its group, draining the groups one at a time so they can be dropped as
+    /// they are processed. It isn't streaming: the whole iterator is still grouped before the first group is yielded,
+    /// see `grouping_by_sorted_runs` for input whose keys are already contiguous.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let mut sizes = Vec::new();
+    /// for (_, group) in [1, 2, 3, 4, 5].iter().grouping_by_lazy(|&&x| x % 2) {
+    ///     sizes.push(group.len());
+    /// }
+    /// sizes.sort();
+    ///
+    /// assert_eq!(sizes, vec![2, 3]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_lazy<K, F>(self, key: F) -> LazyGroups<K, Self::GItem>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash;
+
+    /// Compute the mean absolute deviation of the values of every group, the mean of the absolute
+    /// differences between every value and the mean of its group. A group with a single value has a deviation of `0.0`.
+    ///
+    /// The values of every group are kept until its mean is known, and then dropped once
+    /// the deviation from that mean is computed, so only one `f64` per group is returned.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values of the deviation
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let deviations = [1.0, 2.0, 3.0, 6.0].iter().grouping_by_mad(|_| (), |&&x| x);
+    ///
+    /// // The mean is 3.0 and the absolute deviations are 2, 1, 0 and 3
+    /// assert_eq!(deviations[&()], 1.5);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_mad<K, F, G>(self, key: F, value: G) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64;
+
+    /// Count the occurrences of every value returned by `value` within each group, just like
+    /// `grouping_by_value_counts`, but with both the groups and the values sorted in `BTreeMap`s,
+    /// so the nested frequency tables are fully deterministic.
+    /// It only needs `alloc`, so it is available without the `std` feature.
+    ///
+    /// Params:
+    ///
+    /// `key` -> function to create the keys of the resulting map
+    ///
+    /// `value` -> function to get the values to count
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// let words = ["apple", "avocado", "banana", "apricot"];
+    ///
+    /// let lengths = words.iter().grouping_by_value_counts_ordered(|word| word.chars().next(), |word| word.len());
+    ///
+    /// assert_eq!(
+    ///     format!("{:?}", lengths),
+    ///     "{Some('a'): {5: 1, 7: 2}, Some('b'): {6: 1}}"
+    /// );
+    /// ```
+    fn grouping_by_value_counts_ordered<K, V, F, G>(
+        self,
+        key: F,
+        value: G,
+    ) -> BTreeMap<K, BTreeMap<V, usize>>
+    where
+        K: Ord,
+        V: Ord,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V;
+
+    /// Create a map with one item per key. When two items have the same key, `resolve` receives
+    /// the item already stored and the new one, and returns the item to keep, which may combine both.
+    /// It's just like `to_map` keeping the items themselves as values.
+    ///
+    /// This is an alias of `grouping_by_reduce`, named after its use as a conflict resolver.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// // (name, version)
+    /// let packages = [("serde", 1), ("rand", 8), ("serde", 2), ("rand", 7)];
+    ///
+    /// let latest = packages
+    ///     .iter()
+    ///     .grouping_by_resolve(|p| p.0, |old, new| if new.1 > old.1 { new } else { old });
+    ///
+    /// assert_eq!(
+    ///     latest,
+    ///     [("serde", &("serde", 2)), ("rand", &("rand", 8))]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<&str, &(&str, i32)>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_resolve<K, F, R>(self, key: F, resolve: R) -> HashMap<K, Self::GItem>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        R: FnMut(Self::GItem, Self::GItem) -> Self::GItem;
+
+    /// Count the elements of the iterator given a function, just like `counter`,
+    /// keeping only the keys counted at least `threshold` times, like `HAVING COUNT(*) >= threshold` in SQL.
+    /// Only the counts are stored, the groups themselves are never built.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingBy;
+    /// # use std::collections::HashMap;
+    /// let frequent = [1, 2, 2, 3, 3, 3].iter().grouping_by_counter_above(|&&x| x, 2);
+    ///
+    /// assert_eq!(
+    ///     frequent,
+    ///     [(2, 2), (3, 3)].iter().cloned().collect::<HashMap<i32, usize>>()
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouping_by_counter_above<K, F>(self, key: F, threshold: usize) -> HashMap<K, usize>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K;
+}
+
+#[cfg(feature = "std")]
+mod utilities {
+    use super::{Entry, Hash, HashMap};
+
+    pub fn grouping_by_min_max_aux<T, K, F, C>(
+        iterator: T,
+        mut key: F,
+        mut comparator: C,
+        type_ord: std::cmp::Ordering,
+    ) -> HashMap<K, T::Item>
+    where
+        T: Iterator,
+        K: Eq + Hash,
+        F: FnMut(&T::Item) -> K,
+        C: FnMut(&T::Item, &T::Item) -> std::cmp::Ordering,
+    {
+        let mut map = HashMap::new();
+        iterator.for_each(|item| {
+            let key = key(&item);
+            match map.entry(key) {
+                Entry::Occupied(mut entry) => {
+                    if comparator(&item, entry.get()) == type_ord {
+                        entry.insert(item);
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(item);
+                }
+            }
+        });
+        map
+    }
+
+    pub fn grouping_by_all_min_max_aux<T, K, F, C>(
+        iterator: T,
+        mut key: F,
+        mut comparator: C,
+        type_ord: std::cmp::Ordering,
+    ) -> HashMap<K, Vec<T::Item>>
+    where
+        T: Iterator,
+        K: Eq + Hash,
+        F: FnMut(&T::Item) -> K,
+        C: FnMut(&T::Item, &T::Item) -> std::cmp::Ordering,
+    {
+        let mut map: HashMap<K, Vec<T::Item>> = HashMap::new();
+        iterator.for_each(|item| {
+            let key = key(&item);
+            match map.entry(key) {
+                Entry::Occupied(mut entry) => {
+                    let items = entry.get_mut();
+                    let ord = comparator(&item, &items[0]);
+                    if ord == type_ord {
+                        items.clear();
+                        items.push(item);
+                    } else if ord == std::cmp::Ordering::Equal {
+                        items.push(item);
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(vec![item]);
+                }
+            }
+        });
+        map
+    }
+
+    pub fn grouping_by_rank_aux<T, K, B, F, G>(
+        iterator: T,
+        mut key: F,
+        mut f: G,
+        dense: bool,
+    ) -> HashMap<K, Vec<(T::Item, usize)>>
+    where
+        T: Iterator,
+        K: Eq + Hash,
+        B: Ord,
+        F: FnMut(&T::Item) -> K,
+        G: FnMut(&T::Item) -> B,
+    {
+        let mut map: HashMap<K, Vec<(B, T::Item)>> = HashMap::new();
+        iterator.for_each(|item| {
+            map.entry(key(&item)).or_default().push((f(&item), item));
+        });
+        map.into_iter()
+            .map(|(key, mut group)| {
+                group.sort_by(|(b1, _), (b2, _)| b1.cmp(b2));
+                let mut ranked: Vec<(T::Item, usize)> = Vec::with_capacity(group.len());
+                let mut previous: Option<B> = None;
+                for (position, (b, item)) in group.into_iter().enumerate() {
+                    let rank = match (&previous, ranked.last()) {
+                        (Some(previous), Some(&(_, rank))) if *previous == b => rank,
+                        (_, Some(&(_, rank))) if dense => rank + 1,
+                        _ => position + 1,
+                    };
+                    ranked.push((item, rank));
+                    previous = Some(b);
+                }
+                (key, ranked)
+            })
+            .collect()
+    }
+
+    /// Quantile `q` of the non-empty sorted `values`, interpolating linearly
+    /// between the two closest order statistics
+    pub fn quantile(values: &[f64], q: f64) -> f64 {
+        let position = q * (values.len() - 1) as f64;
+        let lower = values[position.floor() as usize];
+        let upper = values[position.ceil() as usize];
+        lower + (upper - lower) * position.fract()
+    }
+
+    /// Item ordered only by its projected key and, on ties, by its position in the iterator
+    pub struct KeyedItem<B, T> {
+        pub key: B,
+        pub index: usize,
+        pub item: T,
+    }
+
+    impl<B: Ord, T> PartialEq for KeyedItem<B, T> {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == std::cmp::Ordering::Equal
+        }
+    }
+
+    impl<B: Ord, T> Eq for KeyedItem<B, T> {}
+
+    impl<B: Ord, T> PartialOrd for KeyedItem<B, T> {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<B: Ord, T> Ord for KeyedItem<B, T> {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.key.cmp(&other.key).then(self.index.cmp(&other.index))
+        }
+    }
+}
+
+/// Iterator over the runs of contiguous items with the same key, returned by `grouping_by_sorted_runs`
+pub struct SortedRuns<I: Iterator, K, F> {
+    iter: I,
+    key: F,
+    /// First item of the next run, read while looking for the end of the current one
+    pending: Option<(K, I::Item)>,
+}
+
+impl<I, K, F> Iterator for SortedRuns<I, K, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (run_key, first) = match self.pending.take() {
+            Some(pending) => pending,
+            None => {
+                let item = self.iter.next()?;
+                ((self.key)(&item), item)
+            }
+        };
+        let mut run = Vec::new();
+        run.push(first);
+        for item in self.iter.by_ref() {
+            let item_key = (self.key)(&item);
+            if item_key == run_key {
+                run.push(item);
+            } else {
+                self.pending = Some((item_key, item));
+                break;
+            }
+        }
+        Some((run_key, run))
+    }
+}
+
+/// Iterator which drains the groups of an iterator one at a time, returned by `grouping_by_lazy`
+#[cfg(feature = "std")]
+pub struct LazyGroups<K, V> {
+    groups: std::collections::hash_map::IntoIter<K, Vec<V>>,
+}
+
+#[cfg(feature = "std")]
+impl<K, V> Iterator for LazyGroups<K, V> {
+    type Item = (K, Vec<V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.groups.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.groups.size_hint()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V> ExactSizeIterator for LazyGroups<K, V> {}
+
+/// Accumulator which groups the items pushed one at a time by the key function given in `new`.
+/// It produces the same map as `grouping_by`, but doesn't need to hold an iterator.
+///
+/// ## Example
+/// ```rust
+/// # use crate::grouping_by::Grouper;
+/// # use std::collections::HashMap;
+/// let mut grouper = Grouper::new(|number: &i8| number.abs());
+/// grouper.push(-1);
+/// grouper.push(2);
+/// grouper.push(1);
+///
+/// assert_eq!(
+///     grouper.finish(),
+///     [(1, vec![-1, 1]), (2, vec![2])]
+///         .iter()
+///         .cloned()
+///         .collect::<HashMap<i8, Vec<i8>>>()
+/// );
+/// ```
+#[cfg(feature = "std")]
+pub struct Grouper<K, V, F> {
+    map: HashMap<K, Vec<V>>,
+    key: F,
+}
+
+#[cfg(feature = "std")]
+impl<K, V, F> Grouper<K, V, F>
+where
+    K: Eq + Hash,
+    F: FnMut(&V) -> K,
+{
+    /// Create an empty `Grouper` which uses `key` to compute the group of every item
+    pub fn new(key: F) -> Self {
+        Grouper {
+            map: HashMap::new(),
+            key,
+        }
+    }
+
+    /// Add an item to the group of its key
+    pub fn push(&mut self, item: V) {
+        self.map.entry((self.key)(&item)).or_default().push(item);
+    }
+
+    /// Consume the `Grouper` returning the groups accumulated so far
+    pub fn finish(self) -> HashMap<K, Vec<V>> {
+        self.map
+    }
+}
+
+/// Addition which reports overflow instead of wrapping, used by `grouping_by_checked_summing`
+pub trait CheckedAdd: Sized {
+    /// Add `other` to `self`, returning `None` if the result overflows
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_add {
+    ($($t:ty)*) => {
+        $(impl CheckedAdd for $t {
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+        })*
+    };
+}
+
+impl_checked_add!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+/// Addition which clamps to the bounds of the type instead of wrapping, used by `grouping_by_saturating_summing`
+pub trait SaturatingAdd {
+    /// Add `other` to `self`, saturating at the bounds of the type
+    fn saturating_add(self, other: Self) -> Self;
+}
+
+macro_rules! impl_saturating_add {
+    ($($t:ty)*) => {
+        $(impl SaturatingAdd for $t {
+            fn saturating_add(self, other: Self) -> Self {
+                <$t>::saturating_add(self, other)
+            }
+        })*
+    };
+}
+
+impl_saturating_add!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+/// Predicate with a label, used by `grouping_by_count_where`
+#[cfg(feature = "std")]
+pub type LabeledPredicate<'a, T> = (&'a str, Box<dyn Fn(&T) -> bool>);
+
+/// Minimum and maximum elements of a group
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinMax<T> {
+    pub min: T,
+    pub max: T,
+}
+
+/// Numeric summary of the values of a group computed in a single pass, returned by `grouping_by_numeric_summary`.
+/// `m2` is the sum of the squared differences from the mean, updated with Welford's algorithm,
+/// from which the variance is derived without a second pass
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumericSummary {
+    pub count: usize,
+    pub min: f64,
+    pub max: f64,
+    pub sum: f64,
+    pub mean: f64,
+    pub m2: f64,
+}
+
+#[cfg(feature = "std")]
+impl NumericSummary {
+    /// Population variance of the values
+    pub fn variance(&self) -> f64 {
+        self.m2 / self.count as f64
+    }
+
+    /// Population standard deviation of the values
+    pub fn stddev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+/// Aggregation of the items of a group, used by `grouping_by_aggregate`.
+/// `merge` combines two partial aggregations, so they can be computed separately and reduced later
+pub trait Accumulator<T> {
+    /// Add an item to the aggregation
+    fn accept(&mut self, item: &T);
+
+    /// Add all the items already accepted by `other` to the aggregation
+    fn merge(&mut self, other: Self);
+}
+
+/// `Accumulator` counting the items of a group
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CountAcc {
+    pub count: usize,
+}
+
+impl<T> Accumulator<T> for CountAcc {
+    fn accept(&mut self, _: &T) {
+        self.count += 1;
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.count += other.count;
+    }
+}
+
+/// `Accumulator` summing the items of a group, which can be either values or references to them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SumAcc<V> {
+    pub sum: V,
+}
+
+impl<T, V> Accumulator<T> for SumAcc<V>
+where
+    T: core::borrow::Borrow<V>,
+    V: Clone + core::ops::AddAssign,
+{
+    fn accept(&mut self, item: &T) {
+        self.sum += item.borrow().clone();
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.sum += other.sum;
+    }
+}
+
+/// Fallible aggregation of the items of a group, used by `grouping_by_try_aggregate`.
+/// It's the counterpart of `Accumulator` for aggregations which can reject an item, like parsing
+pub trait TryAccumulator<T> {
+    /// Error returned when an item is rejected
+    type Error;
+
+    /// Add an item to the aggregation, or return an error if it can't be added
+    fn try_accept(&mut self, item: &T) -> Result<(), Self::Error>;
+}
+
+/// Contingency table with the number of elements of every pair of row and column,
+/// returned by `grouping_by_crosstab`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg(feature = "std")]
+pub struct Crosstab<R: Eq + Hash, C: Eq + Hash> {
+    counts: HashMap<R, HashMap<C, usize>>,
+    grand_total: usize,
+}
+
+#[cfg(feature = "std")]
+impl<R: Eq + Hash, C: Eq + Hash> Crosstab<R, C> {
+    /// Number of elements in the given row and column
+    pub fn count(&self, row: &R, col: &C) -> usize {
+        self.counts
+            .get(row)
+            .and_then(|cols| cols.get(col))
+            .cloned()
+            .unwrap_or(0)
+    }
+
+    /// Number of elements in the given row
+    pub fn row_total(&self, row: &R) -> usize {
+        self.counts
+            .get(row)
+            .map(|cols| cols.values().sum())
+            .unwrap_or(0)
+    }
+
+    /// Number of elements in the given column
+    pub fn col_total(&self, col: &C) -> usize {
+        self.counts.values().filter_map(|cols| cols.get(col)).sum()
+    }
+
+    /// Number of elements in the whole table
+    pub fn grand_total(&self) -> usize {
+        self.grand_total
+    }
+}
+
+/// Group two iterators by the same key space, like a full outer join.
+/// Every key returned by `ka` or `kb` is present in the resulting map, with the items
+/// of the left iterator in the first `Vec` and the items of the right iterator in the second one.
+/// The keys present in only one of the iterators have an empty `Vec` for the other one.
+///
+/// ## Example
+/// ```rust
+/// # use crate::grouping_by::cogroup_by_key;
+/// let users = vec![(1, "ana"), (2, "bob")];
+/// let orders = vec![(1, 10), (1, 20), (3, 30)];
+///
+/// let cogrouped = cogroup_by_key(users.into_iter(), orders.into_iter(), |u| u.0, |o| o.0);
+///
+/// assert_eq!(cogrouped[&1], (vec![(1, "ana")], vec![(1, 10), (1, 20)]));
+/// assert_eq!(cogrouped[&2], (vec![(2, "bob")], vec![]));
+/// assert_eq!(cogrouped[&3], (vec![], vec![(3, 30)]));
+/// ```
+#[cfg(feature = "std")]
+pub fn cogroup_by_key<A, B, K, FA, FB>(
+    left: impl Iterator<Item = A>,
+    right: impl Iterator<Item = B>,
+    mut ka: FA,
+    mut kb: FB,
+) -> HashMap<K, (Vec<A>, Vec<B>)>
+where
+    K: Eq + Hash,
+    FA: FnMut(&A) -> K,
+    FB: FnMut(&B) -> K,
+{
+    let mut map: HashMap<K, (Vec<A>, Vec<B>)> = HashMap::new();
+    left.for_each(|item| map.entry(ka(&item)).or_default().0.push(item));
+    right.for_each(|item| map.entry(kb(&item)).or_default().1.push(item));
+    map
+}
+
+/// Merge two maps, combining the values of the keys present in both of them with `merge`,
+/// which receives the value of `a` and the value of `b`.
+/// The keys present in only one of the maps keep their value unchanged.
+///
+/// ## Example
+/// ```rust
+/// # use crate::grouping_by::{merge_grouped, GroupingBy};
+/// let a = [1, 2, 2].iter().counter(|&&x| x);
+/// let b = [2, 3].iter().counter(|&&x| x);
+///
+/// let merged = merge_grouped(a, b, |count_a, count_b| count_a + count_b);
+///
+/// assert_eq!(merged, [1, 2, 2, 2, 3].iter().counter(|&&x| x));
+/// ```
+#[cfg(feature = "std")]
+pub fn merge_grouped<K, V, M>(mut a: HashMap<K, V>, b: HashMap<K, V>, mut merge: M) -> HashMap<K, V>
+where
+    K: Eq + Hash,
+    M: FnMut(V, V) -> V,
+{
+    b.into_iter().for_each(|(key, value_b)| {
+        let value = match a.remove(&key) {
+            Some(value_a) => merge(value_a, value_b),
+            None => value_b,
+        };
+        a.insert(key, value);
+    });
+    a
+}
+
+/// Transform the keys of a map with `key_fn`. When several keys are transformed into the same one,
+/// their values are combined with `merge`, in the iteration order of the map.
+/// The values of the keys which don't collide are kept unchanged.
+///
+/// ## Example
+/// ```rust
+/// # use crate::grouping_by::{map_keys, GroupingBy};
+/// let counts = ["a", "B", "A", "b", "c"].iter().counter(|&&x| x);
+///
+/// let counts = map_keys(counts, |key| key.to_lowercase(), |count1, count2| count1 + count2);
+///
+/// assert_eq!(counts["a"], 2);
+/// assert_eq!(counts["b"], 2);
+/// assert_eq!(counts["c"], 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn map_keys<K, K2, V, KF, M>(map: HashMap<K, V>, mut key_fn: KF, mut merge: M) -> HashMap<K2, V>
+where
+    K2: Eq + Hash,
+    KF: FnMut(&K) -> K2,
+    M: FnMut(V, V) -> V,
+{
+    let mut mapped = HashMap::with_capacity(map.len());
+    map.into_iter().for_each(|(key, value)| {
+        let key = key_fn(&key);
+        let value = match mapped.remove(&key) {
+            Some(previous) => merge(previous, value),
+            None => value,
+        };
+        mapped.insert(key, value);
+    });
+    mapped
+}
+
+/// Invert a grouped map: for every value, list the keys of the groups which contain it,
+/// in the iteration order of the map. A value present several times in a group
+/// gets the key of that group once per occurrence.
+///
+/// ## Example
+/// ```rust
+/// # use crate::grouping_by::transpose_groups;
+/// # use std::collections::HashMap;
+/// let tags = [("post", vec!["rust", "iterators"])]
+///     .iter()
+///     .cloned()
+///     .collect::<HashMap<&str, Vec<&str>>>();
+///
+/// let posts = transpose_groups(tags);
+///
+/// assert_eq!(posts["rust"], vec!["post"]);
+/// assert_eq!(posts["iterators"], vec!["post"]);
+/// ```
+#[cfg(feature = "std")]
+pub fn transpose_groups<K, V>(map: HashMap<K, Vec<V>>) -> HashMap<V, Vec<K>>
+where
+    K: Clone,
+    V: Eq + Hash,
+{
+    let mut transposed: HashMap<V, Vec<K>> = HashMap::new();
+    map.into_iter().for_each(|(key, group)| {
+        group.into_iter().for_each(|value| {
+            transposed.entry(value).or_default().push(key.clone());
+        });
+    });
+    transposed
+}
+
+/// Round-robin the items of the groups back into a single sequence: the first item of every group,
+/// then the second item of every group, and so on, skipping the groups already exhausted.
+/// The groups are visited in ascending order of their keys in every round.
+///
+/// ## Example
+/// ```rust
+/// # use crate::grouping_by::{interleave_groups, GroupingBy};
+/// let groups = [1, 2, 3, 4, 5, 7].iter().grouping_by(|&&x| x % 2 == 0);
+///
+/// assert_eq!(interleave_groups(groups), vec![&1, &2, &3, &4, &5, &7]);
+/// ```
+#[cfg(feature = "std")]
+pub fn interleave_groups<K, V>(map: HashMap<K, Vec<V>>) -> Vec<V>
+where
+    K: Ord,
+{
+    let total = map.values().map(Vec::len).sum();
+    let mut groups: Vec<(K, std::vec::IntoIter<V>)> = map
+        .into_iter()
+        .map(|(key, group)| (key, group.into_iter()))
+        .collect();
+    groups.sort_unstable_by(|(key1, _), (key2, _)| key1.cmp(key2));
+    let mut interleaved = Vec::with_capacity(total);
+    while interleaved.len() < total {
+        interleaved.extend(groups.iter_mut().filter_map(|(_, group)| group.next()));
+    }
+    interleaved
+}
+
+impl<T: Iterator> GroupingBy for T {
+    type GItem = T::Item;
+    #[cfg(feature = "std")]
+    fn grouping_by<K, F>(self, key: F) -> HashMap<K, Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        let mut grouper = Grouper::new(key);
+        self.for_each(|item| grouper.push(item));
+        grouper.finish()
+    }
+    #[cfg(feature = "std")]
+    fn grouping_by_as_set<K, F>(self, mut key: F) -> HashMap<K, HashSet<Self::GItem>>
+    where
+        Self::GItem: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        let mut map = HashMap::new();
+        self.for_each(|item| {
+            map.entry(key(&item))
+                .or_insert_with(HashSet::new)
+                .insert(item);
+        });
+        map
+    }
+    #[cfg(feature = "std")]
+    fn counter<K, F>(self, mut key: F) -> HashMap<K, usize>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+    {
+        let mut map = HashMap::new();
+        self.for_each(|item| {
+            *map.entry(key(&item)).or_insert(0) += 1;
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_max<K, F, C>(self, key: F, comparator: C) -> HashMap<K, Self::GItem>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        C: FnMut(&Self::GItem, &Self::GItem) -> std::cmp::Ordering,
+    {
+        utilities::grouping_by_min_max_aux(self, key, comparator, std::cmp::Ordering::Greater)
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_min<K, F, C>(self, key: F, comparator: C) -> HashMap<K, Self::GItem>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        C: FnMut(&Self::GItem, &Self::GItem) -> std::cmp::Ordering,
+    {
+        utilities::grouping_by_min_max_aux(self, key, comparator, std::cmp::Ordering::Less)
+    }
+
+    #[cfg(feature = "std")]
+    fn summing<K, V, F, G>(self, mut key: F, mut value: G) -> HashMap<K, V>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+        V: Default + std::ops::AddAssign,
+    {
+        let mut map: HashMap<K, V> = HashMap::new();
+        self.for_each(|item| {
+            let v = map.entry(key(&item)).or_default();
+            *v += value(&item);
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_try_fold<K, A, E, F, I, Fold>(
+        mut self,
+        mut key: F,
+        mut init: I,
+        mut fold: Fold,
+    ) -> Result<HashMap<K, A>, E>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        I: FnMut() -> A,
+        Fold: FnMut(A, Self::GItem) -> Result<A, E>,
+    {
+        // The accumulators are taken out of their slots while folding, so the key is hashed once per item
+        let mut map: HashMap<K, Option<A>> = HashMap::new();
+        self.try_for_each(|item| {
+            match map.entry(key(&item)) {
+                Entry::Occupied(mut entry) => {
+                    let slot = entry.get_mut();
+                    let acc = slot.take().unwrap_or_else(&mut init);
+                    *slot = Some(fold(acc, item)?);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(Some(fold(init(), item)?));
+                }
+            }
+            Ok(())
+        })?;
+        Ok(map
+            .into_iter()
+            .filter_map(|(key, acc)| acc.map(|acc| (key, acc)))
+            .collect())
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_min_max<K, F, C>(
+        self,
+        mut key: F,
+        mut comparator: C,
+    ) -> HashMap<K, (Self::GItem, Self::GItem)>
+    where
+        Self::GItem: Clone,
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        C: FnMut(&Self::GItem, &Self::GItem) -> std::cmp::Ordering,
+    {
+        let mut map = HashMap::new();
+        self.for_each(|item| match map.entry(key(&item)) {
+            Entry::Occupied(mut entry) => {
+                let (min, max) = entry.get_mut();
+                if comparator(&item, min) == std::cmp::Ordering::Less {
+                    *min = item;
+                } else if comparator(&item, max) == std::cmp::Ordering::Greater {
+                    *max = item;
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert((item.clone(), item));
+            }
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_fractions<K, F>(self, key: F) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+    {
+        let counts = self.counter(key);
+        let total = counts.values().sum::<usize>() as f64;
+        counts
+            .into_iter()
+            .map(|(key, count)| (key, count as f64 / total))
+            .collect()
+    }
+
+    fn grouping_by_ordered<K, F>(self, mut key: F) -> BTreeMap<K, Vec<Self::GItem>>
+    where
+        K: Ord,
+        F: FnMut(&Self::GItem) -> K,
+    {
+        let mut map: BTreeMap<K, Vec<Self::GItem>> = BTreeMap::new();
+        self.for_each(|item| {
+            map.entry(key(&item)).or_default().push(item);
+        });
+        map
+    }
+
+    fn counter_ordered<K, F>(self, mut key: F) -> BTreeMap<K, usize>
+    where
+        K: Ord,
+        F: FnMut(&Self::GItem) -> K,
+    {
+        let mut map = BTreeMap::new();
+        self.for_each(|item| {
+            *map.entry(key(&item)).or_insert(0) += 1;
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn to_map<K, V, KF, VF, M>(self, mut key: KF, mut value: VF, mut merge: M) -> HashMap<K, V>
+    where
+        K: Eq + Hash,
+        KF: FnMut(&Self::GItem) -> K,
+        VF: FnMut(&Self::GItem) -> V,
+        M: FnMut(V, V) -> V,
+    {
+        // The values are taken out of their slots while merging, so the key is hashed once per item
+        let mut map: HashMap<K, Option<V>> = HashMap::new();
+        self.for_each(|item| match map.entry(key(&item)) {
+            Entry::Occupied(mut entry) => {
+                let slot = entry.get_mut();
+                *slot = Some(match slot.take() {
+                    Some(old) => merge(old, value(&item)),
+                    None => value(&item),
+                });
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(Some(value(&item)));
+            }
+        });
+        map.into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key, value)))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn try_to_map<K, V, KF, VF>(
+        mut self,
+        mut key: KF,
+        mut value: VF,
+    ) -> Result<HashMap<K, V>, (K, V, V)>
+    where
+        K: Eq + Hash,
+        KF: FnMut(&Self::GItem) -> K,
+        VF: FnMut(&Self::GItem) -> V,
+    {
+        let mut map = HashMap::new();
+        self.try_for_each(|item| match map.entry(key(&item)) {
+            Entry::Occupied(entry) => {
+                let (key, old) = entry.remove_entry();
+                Err((key, old, value(&item)))
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(value(&item));
+                Ok(())
+            }
+        })?;
+        Ok(map)
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_index<K, F>(self, mut key: F) -> HashMap<K, Vec<usize>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        let mut map = HashMap::new();
+        self.enumerate().for_each(|(index, item)| {
+            map.entry(key(&item)).or_insert_with(Vec::new).push(index);
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_enumerate<K, F>(self, mut key: F) -> HashMap<K, Vec<(usize, Self::GItem)>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        let mut map = HashMap::new();
+        self.enumerate().for_each(|(index, item)| {
+            map.entry(key(&item))
+                .or_insert_with(Vec::new)
+                .push((index, item));
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_into_existing<K, F>(self, map: &mut HashMap<K, Vec<Self::GItem>>, mut key: F)
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        self.for_each(|item| {
+            map.entry(key(&item)).or_default().push(item);
+        });
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_min_n_by_key<K, B, F, G>(
+        self,
+        mut key: F,
+        n: usize,
+        mut f: G,
+    ) -> HashMap<K, Vec<Self::GItem>>
+    where
+        K: Eq + Hash,
+        B: Ord,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> B,
+    {
+        let mut map: HashMap<K, BinaryHeap<utilities::KeyedItem<B, Self::GItem>>> = HashMap::new();
+        self.enumerate().for_each(|(index, item)| {
+            let heap = map.entry(key(&item)).or_default();
+            let keyed = utilities::KeyedItem {
+                key: f(&item),
+                index,
+                item,
+            };
+            if heap.len() < n {
+                heap.push(keyed);
+            } else if let Some(mut max) = heap.peek_mut() {
+                if keyed < *max {
+                    *max = keyed;
+                }
+            }
+        });
+        map.into_iter()
+            .map(|(key, heap)| {
+                let items = heap
+                    .into_sorted_vec()
+                    .into_iter()
+                    .map(|keyed| keyed.item)
+                    .collect();
+                (key, items)
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_skip_none<K, F>(self, mut key: F) -> HashMap<K, Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> Option<K>,
+        K: Eq + Hash,
+    {
+        let mut map = HashMap::new();
+        self.for_each(|item| {
+            if let Some(key) = key(&item) {
+                map.entry(key).or_insert_with(Vec::new).push(item);
+            }
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_weighted_average<K, F, VF, WF>(
+        self,
+        mut key: F,
+        mut value: VF,
+        mut weight: WF,
+    ) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        VF: FnMut(&Self::GItem) -> f64,
+        WF: FnMut(&Self::GItem) -> f64,
+    {
+        let mut map: HashMap<K, (f64, f64)> = HashMap::new();
+        self.for_each(|item| {
+            let (weighted_sum, total_weight) = map.entry(key(&item)).or_default();
+            let weight = weight(&item);
+            *weighted_sum += value(&item) * weight;
+            *total_weight += weight;
+        });
+        map.into_iter()
+            .map(|(key, (weighted_sum, total_weight))| {
+                if total_weight == 0.0 {
+                    (key, f64::NAN)
+                } else {
+                    (key, weighted_sum / total_weight)
+                }
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn counter_with_hasher<K, F, S>(self, mut key: F, hasher: S) -> HashMap<K, usize, S>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        S: BuildHasher,
+    {
+        let mut map = HashMap::with_hasher(hasher);
+        self.for_each(|item| {
+            *map.entry(key(&item)).or_insert(0) += 1;
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_as_set_with_hasher<K, F, S>(
+        self,
+        mut key: F,
+        hasher: S,
+    ) -> HashMap<K, HashSet<Self::GItem, S>, S>
+    where
+        Self::GItem: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+        S: BuildHasher + Clone,
+    {
+        let mut map = HashMap::with_hasher(hasher.clone());
+        self.for_each(|item| {
+            map.entry(key(&item))
+                .or_insert_with(|| HashSet::with_hasher(hasher.clone()))
+                .insert(item);
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_all_max<K, F, C>(self, key: F, comparator: C) -> HashMap<K, Vec<Self::GItem>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        C: FnMut(&Self::GItem, &Self::GItem) -> std::cmp::Ordering,
+    {
+        utilities::grouping_by_all_min_max_aux(self, key, comparator, std::cmp::Ordering::Greater)
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_all_min<K, F, C>(self, key: F, comparator: C) -> HashMap<K, Vec<Self::GItem>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        C: FnMut(&Self::GItem, &Self::GItem) -> std::cmp::Ordering,
+    {
+        utilities::grouping_by_all_min_max_aux(self, key, comparator, std::cmp::Ordering::Less)
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_then_counting<K1, K2, F, G>(
+        self,
+        mut outer: F,
+        mut inner: G,
+    ) -> HashMap<K1, HashMap<K2, usize>>
+    where
+        K1: Eq + Hash,
+        K2: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K1,
+        G: FnMut(&Self::GItem) -> K2,
+    {
+        let mut map: HashMap<K1, HashMap<K2, usize>> = HashMap::new();
+        self.for_each(|item| {
+            *map.entry(outer(&item))
+                .or_default()
+                .entry(inner(&item))
+                .or_insert(0) += 1;
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_then_summing<K1, K2, V, F, G, H>(
+        self,
+        mut outer: F,
+        mut inner: G,
+        mut value: H,
+    ) -> HashMap<K1, HashMap<K2, V>>
+    where
+        K1: Eq + Hash,
+        K2: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K1,
+        G: FnMut(&Self::GItem) -> K2,
+        H: FnMut(&Self::GItem) -> V,
+        V: Default + std::ops::AddAssign,
+    {
+        let mut map: HashMap<K1, HashMap<K2, V>> = HashMap::new();
+        self.for_each(|item| {
+            let v = map
+                .entry(outer(&item))
+                .or_default()
+                .entry(inner(&item))
+                .or_default();
+            *v += value(&item);
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_histogram<F>(
+        self,
+        mut value: F,
+        bins: usize,
+    ) -> (Vec<(f64, f64)>, HashMap<usize, usize>)
+    where
+        F: FnMut(&Self::GItem) -> f64,
+    {
+        assert!(bins > 0, "the number of bins must be greater than 0");
+        let values: Vec<f64> = self
+            .map(|item| value(&item))
+            .filter(|value| value.is_finite())
+            .collect();
+        if values.is_empty() {
+            return (Vec::new(), HashMap::new());
+        }
+        let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        if min == max {
+            return (
+                vec![(min, max)],
+                [(0, values.len())].iter().cloned().collect(),
+            );
+        }
+        let width = (max - min) / bins as f64;
+        let edges = (0..bins)
+            .map(|bin| {
+                let end = if bin + 1 == bins {
+                    max
+                } else {
+                    min + width * (bin + 1) as f64
+                };
+                (min + width * bin as f64, end)
+            })
+            .collect();
+        let counts = values
+            .into_iter()
+            .counter(|&value| (((value - min) / width) as usize).min(bins - 1));
+        (edges, counts)
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_quantiles<K, F, G>(
+        self,
+        mut key: F,
+        mut value: G,
+        qs: &[f64],
+    ) -> HashMap<K, Vec<f64>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64,
+    {
+        assert!(
+            qs.iter().all(|q| (0.0..=1.0).contains(q)),
+            "the quantiles must be in 0.0..=1.0"
+        );
+        let mut map: HashMap<K, Vec<f64>> = HashMap::new();
+        self.for_each(|item| {
+            map.entry(key(&item)).or_default().push(value(&item));
+        });
+        map.into_iter()
+            .map(|(key, mut values)| {
+                values.sort_by(f64::total_cmp);
+                let quantiles = qs
+                    .iter()
+                    .map(|&q| utilities::quantile(&values, q))
+                    .collect();
+                (key, quantiles)
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_unzip<K, L, R, F>(self, mut key: F) -> HashMap<K, (Vec<L>, Vec<R>)>
+    where
+        Self: Iterator<Item = (L, R)>,
+        F: FnMut(&(L, R)) -> K,
+        K: Eq + Hash,
+    {
+        let mut map: HashMap<K, (Vec<L>, Vec<R>)> = HashMap::new();
+        self.for_each(|item| {
+            let (lefts, rights) = map.entry(key(&item)).or_default();
+            let (left, right) = item;
+            lefts.push(left);
+            rights.push(right);
+        });
+        map
+    }
+
+    fn partitioning_by_counting<F>(self, mut predicate: F) -> (usize, usize)
+    where
+        F: FnMut(&Self::GItem) -> bool,
+    {
+        let mut counts = (0, 0);
+        self.for_each(|item| {
+            if predicate(&item) {
+                counts.0 += 1;
+            } else {
+                counts.1 += 1;
+            }
+        });
+        counts
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_min_max_by_key<K, B, F, G>(
+        self,
+        key: F,
+        mut f: G,
+    ) -> HashMap<K, MinMax<Self::GItem>>
+    where
+        Self::GItem: Clone,
+        K: Eq + Hash,
+        B: Ord,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> B,
+    {
+        self.grouping_by_min_max(key, |item1, item2| f(item1).cmp(&f(item2)))
+            .into_iter()
+            .map(|(key, (min, max))| (key, MinMax { min, max }))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_sorted_values<K, F, C>(
+        self,
+        key: F,
+        mut comparator: C,
+    ) -> HashMap<K, Vec<Self::GItem>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        C: FnMut(&Self::GItem, &Self::GItem) -> std::cmp::Ordering,
+    {
+        let mut map = self.grouping_by(key);
+        map.values_mut()
+            .for_each(|items| items.sort_by(&mut comparator));
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_dedup_consecutive<K, F>(self, key: F) -> HashMap<K, Vec<Self::GItem>>
+    where
+        Self::GItem: PartialEq,
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        let mut map = self.grouping_by(key);
+        map.values_mut().for_each(Vec::dedup);
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_geometric_mean<K, F, G>(self, mut key: F, mut value: G) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64,
+    {
+        let mut map: HashMap<K, (f64, usize)> = HashMap::new();
+        self.for_each(|item| {
+            let (log_sum, count) = map.entry(key(&item)).or_default();
+            let value = value(&item);
+            *log_sum += if value > 0.0 { value.ln() } else { f64::NAN };
+            *count += 1;
+        });
+        map.into_iter()
+            .map(|(key, (log_sum, count))| (key, (log_sum / count as f64).exp()))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_harmonic_mean<K, F, G>(self, mut key: F, mut value: G) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64,
+    {
+        // For every key, the sum of the reciprocals of its values, their count and whether any of them is zero
+        let mut map: HashMap<K, (f64, usize, bool)> = HashMap::new();
+        self.for_each(|item| {
+            let (reciprocal_sum, count, any_zero) = map.entry(key(&item)).or_default();
+            let value = value(&item);
+            if value == 0.0 {
+                *any_zero = true;
+            } else {
+                *reciprocal_sum += value.recip();
+            }
+            *count += 1;
+        });
+        map.into_iter()
+            .map(|(key, (reciprocal_sum, count, any_zero))| {
+                let mean = if any_zero {
+                    0.0
+                } else {
+                    count as f64 / reciprocal_sum
+                };
+                (key, mean)
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_value_counts<K, V, F, G>(self, key: F, value: G) -> HashMap<K, HashMap<V, usize>>
+    where
+        K: Eq + Hash,
+        V: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+    {
+        self.grouping_by_then_counting(key, value)
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_summing_tuple<K, V1, V2, F, G>(
+        self,
+        mut key: F,
+        mut values: G,
+    ) -> HashMap<K, (V1, V2)>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> (V1, V2),
+        V1: Default + std::ops::AddAssign,
+        V2: Default + std::ops::AddAssign,
+    {
+        let mut map: HashMap<K, (V1, V2)> = HashMap::new();
+        self.for_each(|item| {
+            let (sum1, sum2) = map.entry(key(&item)).or_default();
+            let (value1, value2) = values(&item);
+            *sum1 += value1;
+            *sum2 += value2;
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_largest_group<K, F>(self, mut key: F) -> Option<(K, usize)>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+    {
+        // For every key, its count and the position at which it reached that count
+        let mut map: HashMap<K, (usize, usize)> = HashMap::new();
+        self.enumerate().for_each(|(index, item)| {
+            let (count, reached_at) = map.entry(key(&item)).or_default();
+            *count += 1;
+            *reached_at = index;
+        });
+        map.into_iter()
+            .min_by(|(_, (count1, reached_at1)), (_, (count2, reached_at2))| {
+                count2.cmp(count1).then(reached_at1.cmp(reached_at2))
+            })
+            .map(|(key, (count, _))| (key, count))
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_filter_groups<K, F, P>(
+        self,
+        key: F,
+        mut predicate: P,
+    ) -> HashMap<K, Vec<Self::GItem>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        P: FnMut(&K, &Vec<Self::GItem>) -> bool,
+    {
+        let mut map = self.grouping_by(key);
+        map.retain(|key, group| predicate(key, group));
+        map
+    }
+
+    #[cfg(feature = "rand")]
+    fn grouping_by_sample<K, F, R>(
+        self,
+        mut key: F,
+        k: usize,
+        rng: &mut R,
+    ) -> HashMap<K, Vec<Self::GItem>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        R: rand::Rng + ?Sized,
+    {
+        // For every key, the number of items seen and the reservoir
+        let mut map: HashMap<K, (usize, Vec<Self::GItem>)> = HashMap::new();
+        self.for_each(|item| {
+            let (seen, reservoir) = map.entry(key(&item)).or_default();
+            *seen += 1;
+            if reservoir.len() < k {
+                reservoir.push(item);
+            } else {
+                let index = rng.gen_range(0..*seen);
+                if index < k {
+                    reservoir[index] = item;
+                }
+            }
+        });
+        map.into_iter()
+            .map(|(key, (_, reservoir))| (key, reservoir))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn counter_into_existing<K, F>(self, map: &mut HashMap<K, usize>, mut key: F)
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+    {
+        self.for_each(|item| {
+            *map.entry(key(&item)).or_insert(0) += 1;
+        });
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_rank<K, B, F, G>(self, key: F, f: G) -> HashMap<K, Vec<(Self::GItem, usize)>>
+    where
+        K: Eq + Hash,
+        B: Ord,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> B,
+    {
+        utilities::grouping_by_rank_aux(self, key, f, false)
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_dense_rank<K, B, F, G>(
+        self,
+        key: F,
+        f: G,
+    ) -> HashMap<K, Vec<(Self::GItem, usize)>>
+    where
+        K: Eq + Hash,
+        B: Ord,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> B,
+    {
+        utilities::grouping_by_rank_aux(self, key, f, true)
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_normalize<K, F, G>(self, mut key: F, mut value: G) -> HashMap<K, Vec<f64>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64,
+    {
+        let mut map: HashMap<K, Vec<f64>> = HashMap::new();
+        self.for_each(|item| {
+            map.entry(key(&item)).or_default().push(value(&item));
+        });
+        map.values_mut().for_each(|values| {
+            let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+            let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let range = max - min;
+            values.iter_mut().for_each(|value| {
+                *value = if range == 0.0 {
+                    0.0
+                } else {
+                    (*value - min) / range
+                }
+            });
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_zscore<K, F, G>(self, mut key: F, mut value: G) -> HashMap<K, Vec<f64>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64,
+    {
+        let mut map: HashMap<K, Vec<f64>> = HashMap::new();
+        self.for_each(|item| {
+            map.entry(key(&item)).or_default().push(value(&item));
+        });
+        map.values_mut().for_each(|values| {
+            let count = values.len() as f64;
+            let mean = values.iter().sum::<f64>() / count;
+            let variance = values
+                .iter()
+                .map(|value| (value - mean).powi(2))
+                .sum::<f64>()
+                / count;
+            let stddev = variance.sqrt();
+            values.iter_mut().for_each(|value| {
+                *value = if stddev == 0.0 {
+                    0.0
+                } else {
+                    (*value - mean) / stddev
+                }
+            });
+        });
+        map
+    }
+
+    fn grouping_by_pivot<R, C, V, FR, FC, FV>(
+        self,
+        mut row: FR,
+        mut col: FC,
+        mut value: FV,
+    ) -> (Vec<R>, Vec<C>, Vec<Vec<Option<V>>>)
+    where
+        R: Ord,
+        C: Ord + Clone,
+        FR: FnMut(&Self::GItem) -> R,
+        FC: FnMut(&Self::GItem) -> C,
+        FV: FnMut(&Self::GItem) -> V,
+    {
+        let mut cells: BTreeMap<R, BTreeMap<C, V>> = BTreeMap::new();
+        self.for_each(|item| {
+            cells
+                .entry(row(&item))
+                .or_default()
+                .insert(col(&item), value(&item));
+        });
+        let cols: Vec<C> = cells
+            .values()
+            .flat_map(|row_cells| row_cells.keys().cloned())
+            .collect::<BTreeSet<C>>()
+            .into_iter()
+            .collect();
+        let mut rows = Vec::with_capacity(cells.len());
+        let mut table = Vec::with_capacity(cells.len());
+        cells.into_iter().for_each(|(row, mut row_cells)| {
+            rows.push(row);
+            table.push(cols.iter().map(|col| row_cells.remove(col)).collect());
+        });
+        (rows, cols, table)
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_entropy<K, V, F, G>(self, key: F, value: G) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        V: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+    {
+        self.grouping_by_value_counts(key, value)
+            .into_iter()
+            .map(|(key, counts)| {
+                let total = counts.values().sum::<usize>() as f64;
+                let entropy = counts
+                    .values()
+                    .map(|&count| {
+                        let p = count as f64 / total;
+                        p * (1.0 / p).log2()
+                    })
+                    .sum::<f64>();
+                (key, entropy)
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_cumulative<K, V, F, G>(self, mut key: F, mut value: G) -> HashMap<K, Vec<V>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+        V: Clone + std::ops::AddAssign,
+    {
+        let mut map: HashMap<K, Vec<V>> = HashMap::new();
+        self.for_each(|item| {
+            let sums = map.entry(key(&item)).or_default();
+            let sum = match sums.last() {
+                Some(last) => {
+                    let mut sum = last.clone();
+                    sum += value(&item);
+                    sum
+                }
+                None => value(&item),
+            };
+            sums.push(sum);
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_collect_string<K, F, G>(self, mut key: F, mut ch: G) -> HashMap<K, String>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> char,
+    {
+        let mut map: HashMap<K, String> = HashMap::new();
+        self.for_each(|item| {
+            map.entry(key(&item)).or_default().push(ch(&item));
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_unique<K, F>(self, mut key: F) -> HashMap<K, Self::GItem>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        // The repeated keys are kept as `None` so their later items are discarded too
+        let mut map = HashMap::new();
+        self.for_each(|item| match map.entry(key(&item)) {
+            Entry::Occupied(mut entry) => {
+                entry.insert(None);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(Some(item));
+            }
+        });
+        map.into_iter()
+            .filter_map(|(key, item)| item.map(|item| (key, item)))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_duplicates<K, F>(self, key: F) -> HashMap<K, Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        self.grouping_by_filter_groups(key, |_, group| group.len() >= 2)
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_chunks<K, F>(self, mut key: F, size: usize) -> HashMap<K, Vec<Vec<Self::GItem>>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        assert!(size != 0, "chunk size must be non-zero");
+        let mut map: HashMap<K, Vec<Vec<Self::GItem>>> = HashMap::new();
+        self.for_each(|item| {
+            let chunks = map.entry(key(&item)).or_default();
+            match chunks.last_mut() {
+                Some(chunk) if chunk.len() < size => chunk.push(item),
+                _ => chunks.push(vec![item]),
+            }
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_take<K, F>(&mut self, n: usize, key: F) -> HashMap<K, Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        self.take(n).grouping_by(key)
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_checked_summing<K, V, F, G>(
+        mut self,
+        mut key: F,
+        mut value: G,
+    ) -> Result<HashMap<K, V>, K>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+        V: Default + CheckedAdd,
+    {
+        let mut map: HashMap<K, V> = HashMap::new();
+        self.try_for_each(|item| {
+            let value = value(&item);
+            match map.entry(key(&item)) {
+                Entry::Occupied(mut entry) => {
+                    match std::mem::take(entry.get_mut()).checked_add(value) {
+                        Some(sum) => {
+                            entry.insert(sum);
+                            Ok(())
+                        }
+                        None => Err(entry.remove_entry().0),
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(value);
+                    Ok(())
+                }
+            }
+        })?;
+        Ok(map)
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_saturating_summing<K, V, F, G>(self, mut key: F, mut value: G) -> HashMap<K, V>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+        V: Default + SaturatingAdd,
+    {
+        let mut map: HashMap<K, V> = HashMap::new();
+        self.for_each(|item| {
+            let sum = map.entry(key(&item)).or_default();
+            *sum = std::mem::take(sum).saturating_add(value(&item));
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_averaging_pairs<K, V, F, G>(
+        self,
+        mut key: F,
+        mut value: G,
+    ) -> HashMap<K, (V, usize)>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+        V: Default + std::ops::AddAssign,
+    {
+        let mut map: HashMap<K, (V, usize)> = HashMap::new();
+        self.for_each(|item| {
+            let (sum, count) = map.entry(key(&item)).or_default();
+            *sum += value(&item);
+            *count += 1;
+        });
+        map
+    }
+
+    fn grouping_by_bitmap<F>(self, mut key: F, max_key: usize) -> Vec<Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> usize,
+    {
+        let mut groups: Vec<Vec<Self::GItem>> = (0..=max_key).map(|_| Vec::new()).collect();
+        self.for_each(|item| {
+            let key = key(&item);
+            assert!(
+                key <= max_key,
+                "key {} is greater than the maximum key {}",
+                key,
+                max_key
+            );
+            groups[key].push(item);
+        });
+        groups
+    }
+
+    #[cfg(feature = "smallvec")]
+    fn grouping_by_smallvec<const N: usize, K, F>(
+        self,
+        mut key: F,
+    ) -> HashMap<K, smallvec::SmallVec<[Self::GItem; N]>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        let mut map: HashMap<K, smallvec::SmallVec<[Self::GItem; N]>> = HashMap::new();
+        self.for_each(|item| {
+            map.entry(key(&item)).or_default().push(item);
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_crosstab<R, C, FR, FC>(self, row: FR, col: FC) -> Crosstab<R, C>
+    where
+        R: Eq + Hash,
+        C: Eq + Hash,
+        FR: FnMut(&Self::GItem) -> R,
+        FC: FnMut(&Self::GItem) -> C,
+    {
+        let counts = self.grouping_by_then_counting(row, col);
+        let grand_total = counts.values().flat_map(|cols| cols.values()).sum();
+        Crosstab {
+            counts,
+            grand_total,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_first_n<K, F>(self, mut key: F, n: usize) -> HashMap<K, Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        let mut map: HashMap<K, Vec<Self::GItem>> = HashMap::new();
+        self.for_each(|item| {
+            let group = map.entry(key(&item)).or_default();
+            if group.len() < n {
+                group.push(item);
+            }
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_last_n<K, F>(self, mut key: F, n: usize) -> HashMap<K, Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        let mut map: HashMap<K, VecDeque<Self::GItem>> = HashMap::new();
+        self.for_each(|item| {
+            let group = map.entry(key(&item)).or_default();
+            if n == 0 {
+                return;
+            }
+            if group.len() == n {
+                group.pop_front();
+            }
+            group.push_back(item);
+        });
+        map.into_iter()
+            .map(|(key, group)| (key, group.into()))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_set_with_count<K, F>(
+        self,
+        mut key: F,
+    ) -> HashMap<K, (HashSet<Self::GItem>, usize)>
+    where
+        Self::GItem: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        let mut map: HashMap<K, (HashSet<Self::GItem>, usize)> = HashMap::new();
+        self.for_each(|item| {
+            let (set, count) = map.entry(key(&item)).or_default();
+            set.insert(item);
+            *count += 1;
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_argmax_sum<K, V, F, G>(self, mut key: F, mut value: G) -> Option<(K, V)>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+        V: Default + std::ops::AddAssign + PartialOrd,
+    {
+        // For every key, its sum and the position at which it reached that sum
+        let mut map: HashMap<K, (V, usize)> = HashMap::new();
+        self.enumerate().for_each(|(index, item)| {
+            let (sum, reached_at) = map.entry(key(&item)).or_default();
+            *sum += value(&item);
+            *reached_at = index;
+        });
+        map.into_iter()
+            .fold(
+                None,
+                |best: Option<(K, (V, usize))>, (key, (sum, reached_at))| match best {
+                    Some((_, (ref best_sum, best_reached_at)))
+                        if *best_sum > sum
+                            || (*best_sum == sum && best_reached_at < reached_at) =>
+                    {
+                        best
+                    }
+                    _ => Some((key, (sum, reached_at))),
+                },
+            )
+            .map(|(key, (sum, _))| (key, sum))
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_ratio_to_max<K, F, G>(self, mut key: F, mut value: G) -> HashMap<K, Vec<f64>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64,
+    {
+        let mut map: HashMap<K, Vec<f64>> = HashMap::new();
+        self.for_each(|item| {
+            map.entry(key(&item)).or_default().push(value(&item));
+        });
+        map.values_mut().for_each(|values| {
+            let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            values.iter_mut().for_each(|value| *value /= max);
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_mapping_into_existing<K, V, F, G>(
+        self,
+        map: &mut HashMap<K, Vec<V>>,
+        mut key: F,
+        mut mapper: G,
+    ) where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(Self::GItem) -> V,
+    {
+        self.for_each(|item| {
+            map.entry(key(&item)).or_default().push(mapper(item));
+        });
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_reduce<K, F, R>(self, mut key: F, mut op: R) -> HashMap<K, Self::GItem>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        R: FnMut(Self::GItem, Self::GItem) -> Self::GItem,
+    {
+        // The items are taken out of their slots while reducing, so the key is hashed once per item
+        let mut map: HashMap<K, Option<Self::GItem>> = HashMap::new();
+        self.for_each(|item| match map.entry(key(&item)) {
+            Entry::Occupied(mut entry) => {
+                let slot = entry.get_mut();
+                *slot = Some(match slot.take() {
+                    Some(acc) => op(acc, item),
+                    None => item,
+                });
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(Some(item));
+            }
+        });
+        map.into_iter()
+            .filter_map(|(key, item)| item.map(|item| (key, item)))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_max_all_by_key<K, B, F, G>(
+        self,
+        key: F,
+        mut f: G,
+    ) -> HashMap<K, Vec<Self::GItem>>
+    where
+        K: Eq + Hash,
+        B: Ord,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> B,
+    {
+        self.grouping_by_all_max(key, |item1, item2| f(item1).cmp(&f(item2)))
+    }
+
+    #[cfg(feature = "std")]
+    fn counter_percentages<K, F>(self, key: F) -> HashMap<K, u8>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+    {
+        self.grouping_by_fractions(key)
+            .into_iter()
+            .map(|(key, fraction)| (key, (fraction * 100.0).round() as u8))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_join_with<K, V, F, G>(
+        self,
+        mut key: F,
+        mut value: G,
+        prefix: &str,
+        sep: &str,
+        suffix: &str,
+    ) -> HashMap<K, String>
+    where
+        K: Eq + Hash,
+        V: std::fmt::Display,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+    {
+        use std::fmt::Write;
+
+        let mut map: HashMap<K, String> = HashMap::new();
+        self.for_each(|item| {
+            let joined = match map.entry(key(&item)) {
+                Entry::Occupied(entry) => {
+                    let joined = entry.into_mut();
+                    joined.push_str(sep);
+                    joined
+                }
+                Entry::Vacant(entry) => entry.insert(prefix.to_string()),
+            };
+            // Writing into a `String` never fails
+            let _ = write!(joined, "{}", value(&item));
+        });
+        map.values_mut().for_each(|joined| joined.push_str(suffix));
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_count_where<K, F>(
+        self,
+        mut key: F,
+        predicates: &[LabeledPredicate<Self::GItem>],
+    ) -> HashMap<K, HashMap<String, usize>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+    {
+        // For every key, the number of matches of every predicate, by its position
+        let mut map: HashMap<K, Vec<usize>> = HashMap::new();
+        self.for_each(|item| {
+            let counts = map
+                .entry(key(&item))
+                .or_insert_with(|| vec![0; predicates.len()]);
+            predicates
+                .iter()
+                .zip(counts.iter_mut())
+                .filter(|((_, predicate), _)| predicate(&item))
+                .for_each(|(_, count)| *count += 1);
+        });
+        map.into_iter()
+            .map(|(key, counts)| {
+                let mut labeled = HashMap::with_capacity(predicates.len());
+                predicates
+                    .iter()
+                    .zip(counts)
+                    .for_each(|((label, _), count)| {
+                        *labeled.entry(label.to_string()).or_insert(0) += count;
+                    });
+                (key, labeled)
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_ranked_by_size<K, F>(self, mut key: F) -> Vec<(K, Vec<Self::GItem>)>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        // For every key, the position of its first item and its items
+        let mut map: HashMap<K, (usize, Vec<Self::GItem>)> = HashMap::new();
+        self.enumerate().for_each(|(index, item)| {
+            map.entry(key(&item))
+                .or_insert_with(|| (index, Vec::new()))
+                .1
+                .push(item);
+        });
+        let mut groups: Vec<_> = map.into_iter().collect();
+        groups.sort_by(|(_, (first1, group1)), (_, (first2, group2))| {
+            group2.len().cmp(&group1.len()).then(first1.cmp(first2))
+        });
+        groups
+            .into_iter()
+            .map(|(key, (_, group))| (key, group))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_as_btreeset<K, F>(self, mut key: F) -> HashMap<K, BTreeSet<Self::GItem>>
+    where
+        Self::GItem: Ord,
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        let mut map: HashMap<K, BTreeSet<Self::GItem>> = HashMap::new();
+        self.for_each(|item| {
+            map.entry(key(&item)).or_default().insert(item);
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_as_vecdeque<K, F>(self, mut key: F) -> HashMap<K, VecDeque<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        let mut map: HashMap<K, VecDeque<Self::GItem>> = HashMap::new();
+        self.for_each(|item| {
+            map.entry(key(&item)).or_default().push_back(item);
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_then_averaging<K1, K2, F, G, H>(
+        self,
+        mut outer: F,
+        mut inner: G,
+        mut value: H,
+    ) -> HashMap<K1, HashMap<K2, f64>>
+    where
+        K1: Eq + Hash,
+        K2: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K1,
+        G: FnMut(&Self::GItem) -> K2,
+        H: FnMut(&Self::GItem) -> f64,
+    {
+        let mut map: HashMap<K1, HashMap<K2, (f64, usize)>> = HashMap::new();
+        self.for_each(|item| {
+            let (sum, count) = map
+                .entry(outer(&item))
+                .or_default()
+                .entry(inner(&item))
+                .or_default();
+            *sum += value(&item);
+            *count += 1;
+        });
+        map.into_iter()
+            .map(|(outer, cells)| {
+                let means = cells
+                    .into_iter()
+                    .map(|(inner, (sum, count))| (inner, sum / count as f64))
+                    .collect();
+                (outer, means)
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_with_finisher<K, R, F, Fin>(self, key: F, mut finisher: Fin) -> HashMap<K, R>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        Fin: FnMut(Vec<Self::GItem>) -> R,
+    {
+        self.grouping_by(key)
+            .into_iter()
+            .map(|(key, group)| (key, finisher(group)))
+            .collect()
+    }
+
+    fn grouping_by_sorted_runs<K, F>(self, key: F) -> SortedRuns<Self, K, F>
+    where
+        Self: Iterator + Sized,
+        K: PartialEq,
+        F: FnMut(&<Self as Iterator>::Item) -> K,
+    {
+        SortedRuns {
+            iter: self,
+            key,
+            pending: None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_smallest_group<K, F>(self, mut key: F) -> Option<(K, usize)>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+    {
+        // For every key, its count and the position at which it first appeared
+        let mut map: HashMap<K, (usize, usize)> = HashMap::new();
+        self.enumerate().for_each(|(index, item)| {
+            map.entry(key(&item)).or_insert((0, index)).0 += 1;
+        });
+        map.into_iter()
+            .min_by_key(|&(_, count_and_first_seen)| count_and_first_seen)
+            .map(|(key, (count, _))| (key, count))
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_running_max<K, B, F, G>(self, mut key: F, mut f: G) -> HashMap<K, Vec<B>>
+    where
+        K: Eq + Hash,
+        B: Ord + Clone,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> B,
+    {
+        let mut map: HashMap<K, Vec<B>> = HashMap::new();
+        self.for_each(|item| {
+            let maxima = map.entry(key(&item)).or_default();
+            let value = f(&item);
+            let max = match maxima.last() {
+                Some(last) if *last > value => last.clone(),
+                _ => value,
+            };
+            maxima.push(max);
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_diff<K, V, F, G>(self, mut key: F, mut value: G) -> HashMap<K, Vec<V>>
+    where
+        K: Eq + Hash,
+        V: std::ops::Sub<Output = V> + Clone,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+    {
+        // For every key, its last value and the differences computed so far
+        let mut map: HashMap<K, (Option<V>, Vec<V>)> = HashMap::new();
+        self.for_each(|item| {
+            let (previous, diffs) = map.entry(key(&item)).or_default();
+            let value = value(&item);
+            if let Some(previous) = previous.replace(value.clone()) {
+                diffs.push(value - previous);
+            }
+        });
+        map.into_iter()
+            .map(|(key, (_, diffs))| (key, diffs))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_collect_result<K, A, E, F, C>(
+        self,
+        mut key: F,
+        mut collector: C,
+    ) -> Result<HashMap<K, A>, E>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        C: FnMut(Vec<Self::GItem>) -> Result<A, E>,
+    {
+        // For every key, the position of its first item and its items
+        let mut map: HashMap<K, (usize, Vec<Self::GItem>)> = HashMap::new();
+        self.enumerate().for_each(|(index, item)| {
+            map.entry(key(&item))
+                .or_insert_with(|| (index, Vec::new()))
+                .1
+                .push(item);
+        });
+        let mut groups: Vec<_> = map.into_iter().collect();
+        groups.sort_unstable_by_key(|&(_, (first, _))| first);
+        groups
+            .into_iter()
+            .map(|(key, (_, group))| Ok((key, collector(group)?)))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_try_aggregate<K, A, F>(self, mut key: F) -> Result<HashMap<K, A>, A::Error>
+    where
+        K: Eq + Hash,
+        A: TryAccumulator<Self::GItem> + Default,
+        F: FnMut(&Self::GItem) -> K,
+    {
+        let mut map: HashMap<K, A> = HashMap::new();
+        for item in self {
+            map.entry(key(&item)).or_default().try_accept(&item)?;
+        }
+        Ok(map)
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_windows<K, F>(self, key: F, size: usize) -> HashMap<K, Vec<Vec<Self::GItem>>>
+    where
+        Self::GItem: Clone,
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        assert!(size != 0, "window size must be non-zero");
+        self.grouping_by(key)
+            .into_iter()
+            .map(|(key, group)| (key, group.windows(size).map(<[_]>::to_vec).collect()))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_mode_all<K, V, F, G>(self, mut key: F, mut value: G) -> HashMap<K, Vec<V>>
+    where
+        K: Eq + Hash,
+        V: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+    {
+        // For every value of every group, its count and the position at which it first appeared
+        let mut map: HashMap<K, HashMap<V, (usize, usize)>> = HashMap::new();
+        self.enumerate().for_each(|(index, item)| {
+            map.entry(key(&item))
+                .or_default()
+                .entry(value(&item))
+                .or_insert((0, index))
+                .0 += 1;
+        });
+        map.into_iter()
+            .map(|(key, counts)| {
+                let max = counts.values().map(|&(count, _)| count).max().unwrap_or(0);
+                let mut modes: Vec<(V, usize)> = counts
+                    .into_iter()
+                    .filter(|&(_, (count, _))| count == max)
+                    .map(|(value, (_, first_seen))| (value, first_seen))
+                    .collect();
+                modes.sort_unstable_by_key(|&(_, first_seen)| first_seen);
+                (key, modes.into_iter().map(|(value, _)| value).collect())
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_aggregate<K, A, F>(self, mut key: F) -> HashMap<K, A>
+    where
+        K: Eq + Hash,
+        A: Accumulator<Self::GItem> + Default,
+        F: FnMut(&Self::GItem) -> K,
+    {
+        let mut map: HashMap<K, A> = HashMap::new();
+        self.for_each(|item| {
+            map.entry(key(&item)).or_default().accept(&item);
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_first_where<K, F, P>(
+        self,
+        mut key: F,
+        mut predicate: P,
+    ) -> HashMap<K, Self::GItem>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        P: FnMut(&Self::GItem) -> bool,
+    {
+        let mut map = HashMap::new();
+        self.for_each(|item| {
+            if let Entry::Vacant(entry) = map.entry(key(&item)) {
+                if predicate(&item) {
+                    entry.insert(item);
+                }
+            }
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_any<K, F, P>(self, mut key: F, mut predicate: P) -> HashMap<K, bool>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        P: FnMut(&Self::GItem) -> bool,
+    {
+        let mut map = HashMap::new();
+        self.for_each(|item| {
+            let any = map.entry(key(&item)).or_insert(false);
+            if !*any {
+                *any = predicate(&item);
+            }
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_all<K, F, P>(self, mut key: F, mut predicate: P) -> HashMap<K, bool>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        P: FnMut(&Self::GItem) -> bool,
+    {
+        let mut map = HashMap::new();
+        self.for_each(|item| {
+            let all = map.entry(key(&item)).or_insert(true);
+            if *all {
+                *all = predicate(&item);
+            }
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_summing_kahan<K, F, G>(self, mut key: F, mut value: G) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64,
+    {
+        // For every key, its sum and the low-order bits lost when adding to it
+        let mut map: HashMap<K, (f64, f64)> = HashMap::new();
+        self.for_each(|item| {
+            let (sum, compensation) = map.entry(key(&item)).or_default();
+            let compensated = value(&item) - *compensation;
+            let new_sum = *sum + compensated;
+            *compensation = (new_sum - *sum) - compensated;
+            *sum = new_sum;
+        });
+        map.into_iter().map(|(key, (sum, _))| (key, sum)).collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_span<K, B, F, G>(self, mut key: F, mut f: G) -> HashMap<K, (B, B, B)>
+    where
+        K: Eq + Hash,
+        B: Ord + std::ops::Sub<Output = B> + Clone,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> B,
+    {
+        let mut map: HashMap<K, (B, B)> = HashMap::new();
+        self.for_each(|item| {
+            let value = f(&item);
+            match map.entry(key(&item)) {
+                Entry::Occupied(mut entry) => {
+                    let (min, max) = entry.get_mut();
+                    if value < *min {
+                        *min = value;
+                    } else if value > *max {
+                        *max = value;
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert((value.clone(), value));
+                }
+            }
+        });
+        map.into_iter()
+            .map(|(key, (min, max))| {
+                let range = max.clone() - min.clone();
+                (key, (min, max, range))
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_count_matching<K, F, P>(self, mut key: F, mut predicate: P) -> HashMap<K, usize>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        P: FnMut(&Self::GItem) -> bool,
+    {
+        let mut map = HashMap::new();
+        self.for_each(|item| {
+            let count = map.entry(key(&item)).or_insert(0);
+            if predicate(&item) {
+                *count += 1;
+            }
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn counter_cumulative<K, F>(self, mut key: F) -> Vec<(K, usize, usize)>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+    {
+        // For every key, the position of its first item and its count
+        let mut map: HashMap<K, (usize, usize)> = HashMap::new();
+        self.enumerate().for_each(|(index, item)| {
+            map.entry(key(&item)).or_insert((index, 0)).1 += 1;
+        });
+        let mut counts: Vec<_> = map.into_iter().collect();
+        counts.sort_unstable_by(|(_, (first1, count1)), (_, (first2, count2))| {
+            count2.cmp(count1).then(first1.cmp(first2))
+        });
+        let mut cumulative = 0;
+        counts
+            .into_iter()
+            .map(|(key, (_, count))| {
+                cumulative += count;
+                (key, count, cumulative)
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_then_max<K1, K2, F, G, C>(
+        self,
+        mut outer: F,
+        mut inner: G,
+        mut comparator: C,
+    ) -> HashMap<K1, HashMap<K2, Self::GItem>>
+    where
+        K1: Eq + Hash,
+        K2: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K1,
+        G: FnMut(&Self::GItem) -> K2,
+        C: FnMut(&Self::GItem, &Self::GItem) -> std::cmp::Ordering,
+    {
+        let mut map: HashMap<K1, HashMap<K2, Self::GItem>> = HashMap::new();
+        self.for_each(|item| {
+            let cells = map.entry(outer(&item)).or_default();
+            match cells.entry(inner(&item)) {
+                Entry::Occupied(mut entry) => {
+                    if comparator(&item, entry.get()) == std::cmp::Ordering::Greater {
+                        entry.insert(item);
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(item);
+                }
+            }
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_top_k_groups<K, F>(self, key: F, k: usize) -> HashMap<K, Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        self.grouping_by_ranked_by_size(key)
+            .into_iter()
+            .take(k)
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_with_other<K, F>(
+        self,
+        key: F,
+        k: usize,
+        other: K,
+    ) -> HashMap<K, Vec<Self::GItem>>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        let mut groups = self.grouping_by_ranked_by_size(key).into_iter();
+        let mut map: HashMap<K, Vec<Self::GItem>> = groups.by_ref().take(k).collect();
+        let mut rest = groups.flat_map(|(_, group)| group).peekable();
+        if rest.peek().is_some() {
+            map.entry(other).or_default().extend(rest);
+        }
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_iqr<K, F, G>(self, key: F, value: G) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64,
+    {
+        self.grouping_by_quantiles(key, value, &[0.25, 0.75])
+            .into_iter()
+            .map(|(key, quartiles)| (key, quartiles[1] - quartiles[0]))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_outliers<K, F, G>(self, mut key: F, mut value: G) -> HashMap<K, Vec<Self::GItem>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64,
+    {
+        let mut map: HashMap<K, Vec<(f64, Self::GItem)>> = HashMap::new();
+        self.for_each(|item| {
+            map.entry(key(&item))
+                .or_default()
+                .push((value(&item), item));
+        });
+        map.into_iter()
+            .filter_map(|(key, group)| {
+                let mut values: Vec<f64> = group.iter().map(|&(value, _)| value).collect();
+                values.sort_by(f64::total_cmp);
+                let q1 = utilities::quantile(&values, 0.25);
+                let q3 = utilities::quantile(&values, 0.75);
+                let fence = 1.5 * (q3 - q1);
+                let outliers: Vec<Self::GItem> = group
+                    .into_iter()
+                    .filter(|&(value, _)| value < q1 - fence || value > q3 + fence)
+                    .map(|(_, item)| item)
+                    .collect();
+                if outliers.is_empty() {
+                    None
+                } else {
+                    Some((key, outliers))
+                }
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn counter_normalized<K, F>(self, key: F, target: f64) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+    {
+        let counts = self.counter(key);
+        let total = counts.values().sum::<usize>() as f64;
+        counts
+            .into_iter()
+            .map(|(key, count)| (key, count as f64 * target / total))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_numeric_summary<K, F, G>(
+        self,
+        mut key: F,
+        mut value: G,
+    ) -> HashMap<K, NumericSummary>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64,
+    {
+        let mut map = HashMap::new();
+        self.for_each(|item| {
+            let value = value(&item);
+            match map.entry(key(&item)) {
+                Entry::Occupied(mut entry) => {
+                    let summary: &mut NumericSummary = entry.get_mut();
+                    summary.count += 1;
+                    summary.min = summary.min.min(value);
+                    summary.max = summary.max.max(value);
+                    summary.sum += value;
+                    let delta = value - summary.mean;
+                    summary.mean += delta / summary.count as f64;
+                    summary.m2 += delta * (value - summary.mean);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(NumericSummary {
+                        count: 1,
+                        min: value,
+                        max: value,
+                        sum: value,
+                        mean: value,
+                        m2: 0.0,
+                    });
+                }
+            }
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_lazy<K, F>(self, key: F) -> LazyGroups<K, Self::GItem>
+    where
+        F: FnMut(&Self::GItem) -> K,
+        K: Eq + Hash,
+    {
+        LazyGroups {
+            groups: self.grouping_by(key).into_iter(),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_mad<K, F, G>(self, mut key: F, mut value: G) -> HashMap<K, f64>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> f64,
+    {
+        let mut map: HashMap<K, Vec<f64>> = HashMap::new();
+        self.for_each(|item| {
+            map.entry(key(&item)).or_default().push(value(&item));
+        });
+        map.into_iter()
+            .map(|(key, values)| {
+                let count = values.len() as f64;
+                let mean = values.iter().sum::<f64>() / count;
+                let deviation =
+                    values.iter().map(|value| (value - mean).abs()).sum::<f64>() / count;
+                (key, deviation)
+            })
+            .collect()
+    }
+
+    fn grouping_by_value_counts_ordered<K, V, F, G>(
+        self,
+        mut key: F,
+        mut value: G,
+    ) -> BTreeMap<K, BTreeMap<V, usize>>
+    where
+        K: Ord,
+        V: Ord,
+        F: FnMut(&Self::GItem) -> K,
+        G: FnMut(&Self::GItem) -> V,
+    {
+        let mut map: BTreeMap<K, BTreeMap<V, usize>> = BTreeMap::new();
+        self.for_each(|item| {
+            *map.entry(key(&item))
+                .or_default()
+                .entry(value(&item))
+                .or_insert(0) += 1;
+        });
+        map
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_resolve<K, F, R>(self, key: F, resolve: R) -> HashMap<K, Self::GItem>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+        R: FnMut(Self::GItem, Self::GItem) -> Self::GItem,
+    {
+        self.grouping_by_reduce(key, resolve)
+    }
+
+    #[cfg(feature = "std")]
+    fn grouping_by_counter_above<K, F>(self, key: F, threshold: usize) -> HashMap<K, usize>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::GItem) -> K,
+    {
+        let mut counts = self.counter(key);
+        counts.retain(|_, count| *count >= threshold);
+        counts
     }
 }
@@ -0,0 +1,82 @@
+//! Grouping of asynchronous streams, available with the `futures` feature.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use futures::{future, stream, FutureExt, Stream, StreamExt};
+
+use crate::Grouper;
+
+type Push<S, K, F> = fn(
+    Grouper<K, <S as Stream>::Item, F>,
+    <S as Stream>::Item,
+) -> future::Ready<Grouper<K, <S as Stream>::Item, F>>;
+
+type Finish<S, K, F> =
+    fn(Grouper<K, <S as Stream>::Item, F>) -> HashMap<K, Vec<<S as Stream>::Item>>;
+
+/// Future returned by `grouping_by_stream`, which drains the stream and resolves to its groups
+pub type GroupingByStreamFuture<S, K, F> = future::Map<
+    stream::Fold<
+        S,
+        future::Ready<Grouper<K, <S as Stream>::Item, F>>,
+        Grouper<K, <S as Stream>::Item, F>,
+        Push<S, K, F>,
+    >,
+    Finish<S, K, F>,
+>;
+
+/// Grouping of the items of a stream, just like `GroupingBy` does for iterators.
+///
+/// It is implemented for any type which implements `futures::Stream`.
+pub trait GroupingByStream {
+    /// The type of the Item of the stream
+    type GItem;
+
+    /// Group the items of the stream by the key function given as parameter, as they arrive.
+    /// The returned future drains the stream and resolves to the same map as `grouping_by`,
+    /// with the items of every group in arrival order
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use crate::grouping_by::GroupingByStream;
+    /// # use std::collections::HashMap;
+    /// let numbers_grouped = futures::executor::block_on(
+    ///     futures::stream::iter(vec![-1i8, -2, 1, 2]).grouping_by_stream(|number| number.abs()),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     numbers_grouped,
+    ///     [(1, vec![-1, 1]), (2, vec![-2, 2])]
+    ///         .iter()
+    ///         .cloned()
+    ///         .collect::<HashMap<i8, Vec<i8>>>()
+    /// );
+    /// ```
+    fn grouping_by_stream<K, F>(self, key: F) -> GroupingByStreamFuture<Self, K, F>
+    where
+        Self: Stream + Sized,
+        F: FnMut(&<Self as Stream>::Item) -> K,
+        K: Eq + Hash;
+}
+
+fn push<K, V, F>(mut grouper: Grouper<K, V, F>, item: V) -> future::Ready<Grouper<K, V, F>>
+where
+    K: Eq + Hash,
+    F: FnMut(&V) -> K,
+{
+    grouper.push(item);
+    future::ready(grouper)
+}
+
+impl<T: Stream> GroupingByStream for T {
+    type GItem = T::Item;
+    fn grouping_by_stream<K, F>(self, key: F) -> GroupingByStreamFuture<Self, K, F>
+    where
+        F: FnMut(&T::Item) -> K,
+        K: Eq + Hash,
+    {
+        self.fold(Grouper::new(key), push as Push<Self, K, F>)
+            .map(Grouper::finish as Finish<Self, K, F>)
+    }
+}
@@ -1,7 +1,14 @@
+#![cfg(feature = "std")]
+
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::hash::BuildHasherDefault;
 
-use grouping_by::GroupingBy;
+use grouping_by::{
+    cogroup_by_key, interleave_groups, map_keys, merge_grouped, transpose_groups, Accumulator,
+    CountAcc, Grouper, GroupingBy, MinMax, SumAcc, TryAccumulator,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
 struct Point {
@@ -168,3 +175,2216 @@ fn test_grouping_by_summing() {
         POINT_ARRAY.iter().summing(|point| point.x, |point| point.y)
     );
 }
+
+#[test]
+fn test_grouping_by_try_fold() {
+    let points_summed: HashMap<i32, i32> = [(4, 4), (5, 13), (18, 9)].iter().cloned().collect();
+
+    assert_eq!(
+        Ok(points_summed),
+        POINT_ARRAY.iter().grouping_by_try_fold(
+            |point| point.x,
+            || 0i32,
+            |acc, point| acc.checked_add(point.y).ok_or(point.x)
+        )
+    );
+}
+
+#[test]
+fn test_grouping_by_try_fold_overflow() {
+    let mut visited = 0;
+    let result = [100i8, 1, 20, 1, 8, 1].iter().grouping_by_try_fold(
+        |&&number| number % 2,
+        || 0i8,
+        |acc, &number| {
+            visited += 1;
+            acc.checked_add(number).ok_or(number)
+        },
+    );
+
+    assert_eq!(Err(8), result);
+    assert_eq!(5, visited);
+}
+
+#[test]
+fn grouping_by_min_max() {
+    let a = VECTOR_ARRAY.iter().grouping_by_min_max(
+        |vector| vector.y,
+        |vector1, vector2| vector1.x.cmp(&vector2.x),
+    );
+    assert_eq!(
+        a,
+        [
+            (
+                2,
+                (&Vector { x: 1, y: 2, z: 4 }, &Vector { x: 2, y: 2, z: 2 })
+            ),
+            (
+                3,
+                (&Vector { x: 1, y: 3, z: 3 }, &Vector { x: 1, y: 3, z: 3 })
+            ),
+        ]
+        .iter()
+        .cloned()
+        .collect()
+    )
+}
+
+#[test]
+fn test_grouping_by_fractions() {
+    let fractions = POINT_ARRAY.iter().grouping_by_fractions(|point| point.x);
+
+    assert_eq!(
+        fractions,
+        [(4, 0.5), (5, 0.25), (18, 0.25)]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i32, f64>>()
+    );
+    assert!((fractions.values().sum::<f64>() - 1.0).abs() < f64::EPSILON);
+    assert!(std::iter::empty::<i32>()
+        .grouping_by_fractions(|&x| x)
+        .is_empty());
+}
+
+#[test]
+fn test_to_map_merge_sum() {
+    let points_summed: HashMap<i32, i32> = [(4, 4), (5, 13), (18, 9)].iter().cloned().collect();
+
+    assert_eq!(
+        points_summed,
+        POINT_ARRAY
+            .iter()
+            .to_map(|point| point.x, |point| point.y, |old, new| old + new)
+    );
+}
+
+#[test]
+fn test_to_map_keep_first() {
+    let first_z: HashMap<i32, i32> = [(2, 4), (3, 3)].iter().cloned().collect();
+
+    assert_eq!(
+        first_z,
+        VECTOR_ARRAY
+            .iter()
+            .to_map(|vector| vector.y, |vector| vector.z, |old, _| old)
+    );
+}
+
+#[test]
+fn test_try_to_map() {
+    let points_y: HashMap<i32, i32> = [(4, 2), (5, 13), (18, 9)].iter().cloned().collect();
+
+    assert_eq!(
+        Ok(points_y),
+        POINT_ARRAY
+            .iter()
+            .skip(1)
+            .try_to_map(|point| point.x, |point| point.y)
+    );
+}
+
+#[test]
+fn test_try_to_map_collision() {
+    assert_eq!(
+        Err((2, 4, 2)),
+        VECTOR_ARRAY
+            .iter()
+            .try_to_map(|vector| vector.y, |vector| vector.z)
+    );
+}
+
+#[test]
+fn test_grouping_by_index() {
+    let indices = [(4, vec![0, 1]), (5, vec![2]), (18, vec![3])]
+        .iter()
+        .cloned()
+        .collect::<HashMap<i32, Vec<usize>>>();
+
+    assert_eq!(
+        indices,
+        POINT_ARRAY.iter().grouping_by_index(|point| point.x)
+    );
+}
+
+#[test]
+fn test_grouping_by_enumerate() {
+    let enumerated = [
+        (
+            2,
+            vec![
+                (0, &VECTOR_ARRAY[0]),
+                (2, &VECTOR_ARRAY[2]),
+                (3, &VECTOR_ARRAY[3]),
+            ],
+        ),
+        (3, vec![(1, &VECTOR_ARRAY[1])]),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<i32, Vec<(usize, &Vector)>>>();
+
+    assert_eq!(
+        enumerated,
+        VECTOR_ARRAY.iter().grouping_by_enumerate(|vector| vector.y)
+    );
+}
+
+#[test]
+fn test_grouper() {
+    let mut grouper = Grouper::new(|point: &&Point| point.x);
+    for point in POINT_ARRAY.iter() {
+        grouper.push(point);
+    }
+
+    assert_eq!(
+        POINT_ARRAY.iter().grouping_by(|point| point.x),
+        grouper.finish()
+    );
+}
+
+#[test]
+fn test_grouper_empty() {
+    let grouper = Grouper::new(|number: &i8| number.abs());
+
+    assert!(grouper.finish().is_empty());
+}
+
+#[test]
+fn test_grouping_by_into_existing() {
+    let mut points_grouped = HashMap::new();
+    POINT_ARRAY[..1]
+        .iter()
+        .grouping_by_into_existing(&mut points_grouped, |point| point.x);
+    POINT_ARRAY[1..]
+        .iter()
+        .grouping_by_into_existing(&mut points_grouped, |point| point.x);
+
+    assert_eq!(
+        POINT_ARRAY.iter().grouping_by(|point| point.x),
+        points_grouped
+    );
+}
+
+#[test]
+fn test_grouping_by_min_n_by_key() {
+    let smallest = [
+        (2, vec![&VECTOR_ARRAY[3], &VECTOR_ARRAY[2]]),
+        (3, vec![&VECTOR_ARRAY[1]]),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<i32, Vec<&Vector>>>();
+
+    assert_eq!(
+        smallest,
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_min_n_by_key(|vector| vector.y, 2, |vector| vector.z)
+    );
+}
+
+#[test]
+fn test_grouping_by_min_n_by_key_zero() {
+    let empty = [(2, vec![]), (3, vec![])]
+        .iter()
+        .cloned()
+        .collect::<HashMap<i32, Vec<&Vector>>>();
+
+    assert_eq!(
+        empty,
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_min_n_by_key(|vector| vector.y, 0, |vector| vector.z)
+    );
+}
+
+#[test]
+fn test_grouping_by_min_n_by_key_group_size() {
+    let sorted = [
+        (
+            2,
+            vec![&VECTOR_ARRAY[3], &VECTOR_ARRAY[2], &VECTOR_ARRAY[0]],
+        ),
+        (3, vec![&VECTOR_ARRAY[1]]),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<i32, Vec<&Vector>>>();
+
+    assert_eq!(
+        sorted,
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_min_n_by_key(|vector| vector.y, 3, |vector| vector.z)
+    );
+}
+
+#[test]
+fn test_grouping_by_min_n_by_key_ties() {
+    let first_ties = [
+        (2, vec![&VECTOR_ARRAY[0], &VECTOR_ARRAY[2]]),
+        (3, vec![&VECTOR_ARRAY[1]]),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<i32, Vec<&Vector>>>();
+
+    assert_eq!(
+        first_ties,
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_min_n_by_key(|vector| vector.y, 2, |vector| vector.y)
+    );
+}
+
+#[test]
+fn test_grouping_by_skip_none() {
+    let points_grouped = [
+        (5, vec![&Point { x: 5, y: 13 }]),
+        (18, vec![&Point { x: 18, y: 9 }]),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<i32, Vec<&Point>>>();
+
+    assert_eq!(
+        points_grouped,
+        POINT_ARRAY
+            .iter()
+            .grouping_by_skip_none(|point| if point.y > 2 { Some(point.x) } else { None })
+    );
+}
+
+#[test]
+fn test_grouping_by_weighted_average() {
+    let averages = VECTOR_ARRAY.iter().grouping_by_weighted_average(
+        |vector| vector.y,
+        |vector| vector.z as f64,
+        |vector| vector.x as f64,
+    );
+
+    assert_eq!(
+        averages,
+        [(2, 2.0), (3, 3.0)]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i32, f64>>()
+    );
+}
+
+#[test]
+fn test_grouping_by_weighted_average_zero_weight() {
+    let averages = POINT_ARRAY.iter().grouping_by_weighted_average(
+        |point| point.x,
+        |point| point.y as f64,
+        |_| 0.0,
+    );
+
+    assert_eq!(3, averages.len());
+    assert!(averages.values().all(|average| average.is_nan()));
+}
+
+#[test]
+fn test_counter_with_hasher() {
+    let numbers_counted = [1i8, 2, 2, 3, 4]
+        .iter()
+        .counter_with_hasher(|&&x| x, BuildHasherDefault::<DefaultHasher>::default());
+
+    assert_eq!(
+        numbers_counted,
+        [(1, 1), (2, 2), (3, 1), (4, 1)]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i8, usize, BuildHasherDefault<DefaultHasher>>>()
+    )
+}
+
+#[test]
+fn test_grouping_by_as_set_with_hasher() {
+    let points_grouped = POINT_ARRAY.iter().grouping_by_as_set_with_hasher(
+        |point| point.x,
+        BuildHasherDefault::<DefaultHasher>::default(),
+    );
+
+    assert_eq!(3, points_grouped.len());
+    assert_eq!(
+        [&Point { x: 4, y: 2 }]
+            .iter()
+            .cloned()
+            .collect::<HashSet<&Point, BuildHasherDefault<DefaultHasher>>>(),
+        points_grouped[&4]
+    );
+}
+
+#[test]
+fn grouping_by_all_max() {
+    let a = VECTOR_ARRAY.iter().grouping_by_all_max(
+        |vector| vector.y,
+        |vector1, vector2| vector1.x.cmp(&vector2.x),
+    );
+    assert_eq!(
+        a,
+        [
+            (
+                2,
+                vec![&Vector { x: 2, y: 2, z: 2 }, &Vector { x: 2, y: 2, z: 1 }]
+            ),
+            (3, vec![&Vector { x: 1, y: 3, z: 3 }]),
+        ]
+        .iter()
+        .cloned()
+        .collect()
+    )
+}
+
+#[test]
+fn grouping_by_all_min() {
+    let a = VECTOR_ARRAY.iter().grouping_by_all_min(
+        |vector| vector.x,
+        |vector1, vector2| vector1.y.cmp(&vector2.y),
+    );
+    assert_eq!(
+        a,
+        [
+            (1, vec![&Vector { x: 1, y: 2, z: 4 }]),
+            (
+                2,
+                vec![&Vector { x: 2, y: 2, z: 2 }, &Vector { x: 2, y: 2, z: 1 }]
+            ),
+        ]
+        .iter()
+        .cloned()
+        .collect()
+    )
+}
+
+#[test]
+fn grouping_by_all_min_reset() {
+    let a = [3, 1, 3, 1, 0]
+        .iter()
+        .grouping_by_all_min(|_| (), |a, b| a.cmp(b));
+
+    assert_eq!(a, [((), vec![&0])].iter().cloned().collect())
+}
+
+#[test]
+fn test_grouping_by_then_counting() {
+    let counts = [
+        (true, [(false, 2), (true, 1)].iter().cloned().collect()),
+        (false, [(true, 1)].iter().cloned().collect()),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<bool, HashMap<bool, usize>>>();
+
+    assert_eq!(
+        counts,
+        POINT_ARRAY
+            .iter()
+            .grouping_by_then_counting(|point| point.x % 2 == 0, |point| point.y > 5)
+    );
+}
+
+#[test]
+fn test_grouping_by_then_summing() {
+    let sums = [
+        (1, [(2, 4), (3, 3)].iter().cloned().collect()),
+        (2, [(2, 3)].iter().cloned().collect()),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<i32, HashMap<i32, i32>>>();
+
+    assert_eq!(
+        sums,
+        VECTOR_ARRAY.iter().grouping_by_then_summing(
+            |vector| vector.x,
+            |vector| vector.y,
+            |vector| vector.z
+        )
+    );
+}
+
+#[test]
+fn test_grouping_by_histogram() {
+    let (edges, counts) = POINT_ARRAY
+        .iter()
+        .grouping_by_histogram(|point| point.y as f64, 4);
+
+    assert_eq!(
+        vec![(2.0, 4.75), (4.75, 7.5), (7.5, 10.25), (10.25, 13.0)],
+        edges
+    );
+    assert_eq!(
+        [(0, 2), (2, 1), (3, 1)]
+            .iter()
+            .cloned()
+            .collect::<HashMap<usize, usize>>(),
+        counts
+    );
+}
+
+#[test]
+fn test_grouping_by_histogram_equal_values() {
+    let (edges, counts) = POINT_ARRAY[..2]
+        .iter()
+        .grouping_by_histogram(|point| point.x as f64, 3);
+
+    assert_eq!(vec![(4.0, 4.0)], edges);
+    assert_eq!(
+        [(0, 2)].iter().cloned().collect::<HashMap<usize, usize>>(),
+        counts
+    );
+}
+
+#[test]
+fn test_grouping_by_quantiles() {
+    let latencies = [
+        ("a", 10.0),
+        ("a", 30.0),
+        ("b", 5.0),
+        ("a", 20.0),
+        ("a", 50.0),
+        ("a", 40.0),
+    ];
+
+    let quantiles = latencies.iter().grouping_by_quantiles(
+        |latency| latency.0,
+        |latency| latency.1,
+        &[0.5, 0.9],
+    );
+
+    assert_eq!(
+        [("a", vec![30.0, 46.0]), ("b", vec![5.0, 5.0])]
+            .iter()
+            .cloned()
+            .collect::<HashMap<&str, Vec<f64>>>(),
+        quantiles
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_grouping_by_quantiles_out_of_range() {
+    POINT_ARRAY
+        .iter()
+        .grouping_by_quantiles(|point| point.x, |point| point.y as f64, &[1.5]);
+}
+
+#[test]
+fn test_cogroup_by_key() {
+    let cogrouped = cogroup_by_key(
+        POINT_ARRAY.iter(),
+        VECTOR_ARRAY.iter(),
+        |point| point.y,
+        |vector| vector.y,
+    );
+
+    assert_eq!(
+        [
+            (
+                2,
+                (
+                    vec![&POINT_ARRAY[0], &POINT_ARRAY[1]],
+                    vec![&VECTOR_ARRAY[0], &VECTOR_ARRAY[2], &VECTOR_ARRAY[3]]
+                )
+            ),
+            (3, (vec![], vec![&VECTOR_ARRAY[1]])),
+            (9, (vec![&POINT_ARRAY[3]], vec![])),
+            (13, (vec![&POINT_ARRAY[2]], vec![])),
+        ]
+        .iter()
+        .cloned()
+        .collect::<HashMap<i32, (Vec<&Point>, Vec<&Vector>)>>(),
+        cogrouped
+    );
+}
+
+#[test]
+fn test_grouping_by_unzip() {
+    let pairs = [('a', 1), ('b', 2), ('a', 3), ('c', 4), ('b', 5)];
+
+    assert_eq!(
+        [
+            ('a', (vec!['a', 'a'], vec![1, 3])),
+            ('b', (vec!['b', 'b'], vec![2, 5])),
+            ('c', (vec!['c'], vec![4])),
+        ]
+        .iter()
+        .cloned()
+        .collect::<HashMap<char, (Vec<char>, Vec<i32>)>>(),
+        pairs.iter().cloned().grouping_by_unzip(|pair| pair.0)
+    );
+}
+
+#[test]
+fn test_partitioning_by_counting() {
+    assert_eq!(
+        (2, 2),
+        POINT_ARRAY
+            .iter()
+            .partitioning_by_counting(|point| point.y > 5)
+    );
+    assert_eq!(
+        (0, 0),
+        POINT_ARRAY[..0]
+            .iter()
+            .partitioning_by_counting(|point| point.y > 5)
+    );
+}
+
+#[test]
+fn grouping_by_min_max_by_key() {
+    let a = VECTOR_ARRAY
+        .iter()
+        .grouping_by_min_max_by_key(|vector| vector.y, |vector| vector.z);
+    assert_eq!(
+        a,
+        [
+            (
+                2,
+                MinMax {
+                    min: &Vector { x: 2, y: 2, z: 1 },
+                    max: &Vector { x: 1, y: 2, z: 4 }
+                }
+            ),
+            (
+                3,
+                MinMax {
+                    min: &Vector { x: 1, y: 3, z: 3 },
+                    max: &Vector { x: 1, y: 3, z: 3 }
+                }
+            ),
+        ]
+        .iter()
+        .cloned()
+        .collect()
+    )
+}
+
+#[test]
+fn test_grouping_by_sorted_values() {
+    let sorted = [
+        (
+            2,
+            vec![
+                &Vector { x: 2, y: 2, z: 1 },
+                &Vector { x: 2, y: 2, z: 2 },
+                &Vector { x: 1, y: 2, z: 4 },
+            ],
+        ),
+        (3, vec![&Vector { x: 1, y: 3, z: 3 }]),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<i32, Vec<&Vector>>>();
+
+    assert_eq!(
+        sorted,
+        VECTOR_ARRAY.iter().grouping_by_sorted_values(
+            |vector| vector.y,
+            |vector1, vector2| vector1.z.cmp(&vector2.z)
+        )
+    );
+}
+
+#[test]
+fn test_grouping_by_sorted_values_stable() {
+    let sorted = VECTOR_ARRAY.iter().grouping_by_sorted_values(
+        |vector| vector.y,
+        |vector1, vector2| vector2.x.cmp(&vector1.x),
+    );
+
+    assert_eq!(
+        vec![&VECTOR_ARRAY[2], &VECTOR_ARRAY[3], &VECTOR_ARRAY[0]],
+        sorted[&2]
+    );
+}
+
+#[test]
+fn test_grouping_by_dedup_consecutive() {
+    // The items of the `true` group are 1, 1, 2, 1, 1
+    let numbers = [1, 10, 1, 2, 10, 10, 1, 1];
+
+    assert_eq!(
+        [(true, vec![&1, &2, &1]), (false, vec![&10])]
+            .iter()
+            .cloned()
+            .collect::<HashMap<bool, Vec<&i32>>>(),
+        numbers
+            .iter()
+            .grouping_by_dedup_consecutive(|&&number| number < 10)
+    );
+}
+
+#[test]
+fn test_grouping_by_geometric_mean() {
+    let means = POINT_ARRAY
+        .iter()
+        .grouping_by_geometric_mean(|point| point.x, |point| point.y as f64);
+
+    assert_eq!(3, means.len());
+    assert!((means[&4] - 2.0).abs() < 1e-10);
+    assert!((means[&5] - 13.0).abs() < 1e-10);
+
+    let rates = [("a", 1.0), ("a", 4.0), ("b", 2.0), ("b", 0.0)];
+    let means = rates
+        .iter()
+        .grouping_by_geometric_mean(|rate| rate.0, |rate| rate.1);
+
+    assert!((means["a"] - 2.0).abs() < 1e-10);
+    assert!(means["b"].is_nan());
+}
+
+#[test]
+fn test_grouping_by_harmonic_mean() {
+    let means = [1.0, 2.0, 4.0, 5.0]
+        .iter()
+        .grouping_by_harmonic_mean(|&&x| x < 4.5, |&&x| x);
+
+    assert!((means[&true] - 12.0 / 7.0).abs() < 1e-10);
+    assert!((means[&false] - 5.0).abs() < 1e-10);
+
+    let means = [1.0, 0.0].iter().grouping_by_harmonic_mean(|_| (), |&&x| x);
+
+    assert_eq!(0.0, means[&()]);
+}
+
+#[test]
+fn test_grouping_by_harmonic_mean_zero() {
+    // The reciprocal of -0.0 is negative infinity, but a zero of either sign gives a mean of 0.0
+    let means = [2.0, -0.0, 4.0, 0.0, 1.0]
+        .iter()
+        .grouping_by_harmonic_mean(|&&x| x >= 2.0, |&&x| x);
+
+    assert_eq!(0.0, means[&false]);
+    assert!(means[&false].is_sign_positive());
+    assert!((means[&true] - 8.0 / 3.0).abs() < 1e-10);
+}
+
+#[test]
+fn test_grouping_by_value_counts() {
+    let counts = [
+        (4, [(2, 2)].iter().cloned().collect()),
+        (5, [(13, 1)].iter().cloned().collect()),
+        (18, [(9, 1)].iter().cloned().collect()),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<i32, HashMap<i32, usize>>>();
+
+    assert_eq!(
+        counts,
+        POINT_ARRAY
+            .iter()
+            .grouping_by_value_counts(|point| point.x, |point| point.y)
+    );
+}
+
+#[test]
+fn test_grouping_by_summing_tuple() {
+    let sums: HashMap<i32, (i32, i32)> = [(1, (5, 7)), (2, (4, 3))].iter().cloned().collect();
+
+    assert_eq!(
+        sums,
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_summing_tuple(|vector| vector.x, |vector| (vector.y, vector.z))
+    );
+}
+
+#[test]
+fn test_grouping_by_largest_group() {
+    assert_eq!(
+        Some((4, 2)),
+        POINT_ARRAY
+            .iter()
+            .grouping_by_largest_group(|point| point.x)
+    );
+    assert_eq!(
+        None,
+        POINT_ARRAY[..0]
+            .iter()
+            .grouping_by_largest_group(|point| point.x)
+    );
+}
+
+#[test]
+fn test_grouping_by_largest_group_tie() {
+    // Both 3 and 1 have two items, but 3 gets its second one first
+    assert_eq!(
+        Some((3, 2)),
+        [1, 3, 3, 2, 1].iter().grouping_by_largest_group(|&&x| x)
+    );
+}
+
+#[test]
+fn test_grouping_by_filter_groups() {
+    let points_grouped = [(4, vec![&Point { x: 4, y: 2 }, &Point { x: 4, y: 2 }])]
+        .iter()
+        .cloned()
+        .collect::<HashMap<i32, Vec<&Point>>>();
+
+    assert_eq!(
+        points_grouped,
+        POINT_ARRAY
+            .iter()
+            .grouping_by_filter_groups(|point| point.x, |_, group| group.len() > 1)
+    );
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_grouping_by_sample() {
+    use rand::SeedableRng;
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    let sample = POINT_ARRAY
+        .iter()
+        .grouping_by_sample(|point| point.y > 5, 1, &mut rng);
+
+    assert_eq!(1, sample[&true].len());
+    assert_eq!(
+        vec![&Point { x: 4, y: 2 }, &Point { x: 4, y: 2 }],
+        POINT_ARRAY
+            .iter()
+            .grouping_by_sample(|point| point.y > 5, 3, &mut rng)[&false]
+    );
+
+    // Every item of a group of 4 should be sampled about a quarter of the times
+    let mut counts = HashMap::new();
+    for _ in 0..4000 {
+        let sample = (0..8).grouping_by_sample(|x| x % 2, 1, &mut rng);
+        *counts.entry(sample[&0][0]).or_insert(0) += 1;
+    }
+    assert_eq!(4, counts.len());
+    assert!(counts.values().all(|&count| (800..1200).contains(&count)));
+}
+
+#[test]
+fn test_counter_into_existing() {
+    let mut points_counted = HashMap::new();
+    POINT_ARRAY[..1]
+        .iter()
+        .counter_into_existing(&mut points_counted, |point| point.x);
+    POINT_ARRAY[1..]
+        .iter()
+        .counter_into_existing(&mut points_counted, |point| point.x);
+
+    assert_eq!(POINT_ARRAY.iter().counter(|point| point.x), points_counted);
+}
+
+#[test]
+fn test_grouping_by_rank() {
+    let ranks = [
+        (
+            2,
+            vec![
+                (&Vector { x: 1, y: 2, z: 4 }, 1),
+                (&Vector { x: 2, y: 2, z: 2 }, 2),
+                (&Vector { x: 2, y: 2, z: 1 }, 2),
+            ],
+        ),
+        (3, vec![(&Vector { x: 1, y: 3, z: 3 }, 1)]),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<i32, Vec<(&Vector, usize)>>>();
+
+    assert_eq!(
+        ranks,
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_rank(|vector| vector.y, |vector| vector.x)
+    );
+}
+
+#[test]
+fn test_grouping_by_rank_tie() {
+    let ranks = VECTOR_ARRAY
+        .iter()
+        .grouping_by_rank(|vector| vector.y, |vector| -vector.x);
+
+    assert_eq!(
+        vec![
+            (&Vector { x: 2, y: 2, z: 2 }, 1),
+            (&Vector { x: 2, y: 2, z: 1 }, 1),
+            (&Vector { x: 1, y: 2, z: 4 }, 3),
+        ],
+        ranks[&2]
+    );
+}
+
+#[test]
+fn test_grouping_by_dense_rank() {
+    let ranks = VECTOR_ARRAY
+        .iter()
+        .grouping_by_dense_rank(|vector| vector.y, |vector| -vector.x);
+
+    assert_eq!(
+        vec![
+            (&Vector { x: 2, y: 2, z: 2 }, 1),
+            (&Vector { x: 2, y: 2, z: 1 }, 1),
+            (&Vector { x: 1, y: 2, z: 4 }, 2),
+        ],
+        ranks[&2]
+    );
+    assert_eq!(vec![(&Vector { x: 1, y: 3, z: 3 }, 1)], ranks[&3]);
+}
+
+#[test]
+fn test_grouping_by_normalize() {
+    let scaled = [(2, vec![1.0, 1.0 / 3.0, 0.0]), (3, vec![0.0])]
+        .iter()
+        .cloned()
+        .collect::<HashMap<i32, Vec<f64>>>();
+
+    assert_eq!(
+        scaled,
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_normalize(|vector| vector.y, |vector| vector.z as f64)
+    );
+}
+
+#[test]
+fn test_grouping_by_zscore() {
+    let scores = VECTOR_ARRAY
+        .iter()
+        .grouping_by_zscore(|vector| vector.y, |vector| vector.z as f64);
+
+    assert_eq!(3, scores[&2].len());
+    assert!(scores[&2].iter().sum::<f64>().abs() < 1e-10);
+    assert!(scores[&2][0] > 0.0 && scores[&2][2] < 0.0);
+    assert_eq!(vec![0.0], scores[&3]);
+}
+
+#[test]
+fn test_grouping_by_pivot() {
+    let (rows, cols, table) = VECTOR_ARRAY.iter().grouping_by_pivot(
+        |vector| vector.y,
+        |vector| vector.x,
+        |vector| vector.z,
+    );
+
+    assert_eq!(vec![2, 3], rows);
+    assert_eq!(vec![1, 2], cols);
+    // The cell (2, 2) has two items, and keeps the last one
+    assert_eq!(vec![vec![Some(4), Some(1)], vec![Some(3), None]], table);
+}
+
+#[test]
+fn test_grouping_by_entropy() {
+    // The group 2 has the x values 1, 2 and 2
+    let entropies = VECTOR_ARRAY
+        .iter()
+        .grouping_by_entropy(|vector| vector.y, |vector| vector.x);
+
+    assert!((entropies[&2] - 0.9182958340544896).abs() < 1e-10);
+    assert_eq!(0.0, entropies[&3]);
+    assert!(entropies[&3].is_sign_positive());
+
+    let uniform = [1, 2, 1, 2].iter().grouping_by_entropy(|_| (), |&&x| x);
+    assert_eq!(1.0, uniform[&()]);
+}
+
+#[test]
+fn test_grouping_by_cumulative() {
+    let running = VECTOR_ARRAY
+        .iter()
+        .grouping_by_cumulative(|vector| vector.y, |vector| vector.z);
+
+    assert_eq!(vec![4, 6, 7], running[&2]);
+    assert_eq!(vec![3], running[&3]);
+}
+
+#[test]
+fn test_grouping_by_collect_string() {
+    let strings = "Hello, world 42!"
+        .chars()
+        .grouping_by_collect_string(|c| c.is_alphabetic(), |&c| c);
+
+    assert_eq!(
+        [
+            (true, "Helloworld".to_string()),
+            (false, ",  42!".to_string())
+        ]
+        .iter()
+        .cloned()
+        .collect::<HashMap<bool, String>>(),
+        strings
+    );
+}
+
+#[test]
+fn test_grouping_by_unique() {
+    let unique = [(5, &Point { x: 5, y: 13 }), (18, &Point { x: 18, y: 9 })]
+        .iter()
+        .cloned()
+        .collect::<HashMap<i32, &Point>>();
+
+    assert_eq!(
+        unique,
+        POINT_ARRAY.iter().grouping_by_unique(|point| point.x)
+    );
+}
+
+#[test]
+fn test_grouping_by_duplicates() {
+    let duplicates = [(4, vec![&Point { x: 4, y: 2 }, &Point { x: 4, y: 2 }])]
+        .iter()
+        .cloned()
+        .collect::<HashMap<i32, Vec<&Point>>>();
+
+    assert_eq!(
+        duplicates,
+        POINT_ARRAY.iter().grouping_by_duplicates(|point| point.x)
+    );
+}
+
+#[test]
+fn test_grouping_by_chunks() {
+    let chunks = [
+        (
+            2,
+            vec![
+                vec![&VECTOR_ARRAY[0], &VECTOR_ARRAY[2]],
+                vec![&VECTOR_ARRAY[3]],
+            ],
+        ),
+        (3, vec![vec![&VECTOR_ARRAY[1]]]),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<i32, Vec<Vec<&Vector>>>>();
+
+    assert_eq!(
+        chunks,
+        VECTOR_ARRAY.iter().grouping_by_chunks(|vector| vector.y, 2)
+    );
+}
+
+#[test]
+fn test_grouping_by_chunks_huge_size() {
+    let chunks = VECTOR_ARRAY
+        .iter()
+        .grouping_by_chunks(|vector| vector.y, usize::MAX);
+
+    assert_eq!(
+        vec![vec![&VECTOR_ARRAY[0], &VECTOR_ARRAY[2], &VECTOR_ARRAY[3]]],
+        chunks[&2]
+    );
+    assert_eq!(vec![vec![&VECTOR_ARRAY[1]]], chunks[&3]);
+}
+
+#[test]
+#[should_panic]
+fn test_grouping_by_chunks_zero() {
+    VECTOR_ARRAY.iter().grouping_by_chunks(|vector| vector.y, 0);
+}
+
+#[test]
+fn test_grouping_by_take() {
+    let mut numbers = [1i8, -1, 2, -2, 3].iter();
+
+    assert_eq!(
+        [(1, vec![&1, &-1]), (2, vec![&2])]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i8, Vec<&i8>>>(),
+        numbers.grouping_by_take(3, |number| number.abs())
+    );
+    assert_eq!(vec![&-2, &3], numbers.collect::<Vec<&i8>>());
+}
+
+#[test]
+fn test_grouping_by_checked_summing() {
+    let points_summed: HashMap<i32, i32> = [(4, 4), (5, 13), (18, 9)].iter().cloned().collect();
+
+    assert_eq!(
+        Ok(points_summed),
+        POINT_ARRAY
+            .iter()
+            .grouping_by_checked_summing(|point| point.x, |point| point.y)
+    );
+}
+
+#[test]
+fn test_grouping_by_checked_summing_overflow() {
+    let numbers = [("a", 100i8), ("b", 1), ("b", -100), ("a", 27), ("a", 1)];
+
+    assert_eq!(
+        Err("a"),
+        numbers
+            .iter()
+            .grouping_by_checked_summing(|number| number.0, |number| number.1)
+    );
+}
+
+#[test]
+fn test_grouping_by_saturating_summing() {
+    let numbers = [("a", 200u8), ("b", 1), ("a", 50), ("b", 2), ("a", 10)];
+
+    assert_eq!(
+        [("a", 255), ("b", 3)]
+            .iter()
+            .cloned()
+            .collect::<HashMap<&str, u8>>(),
+        numbers
+            .iter()
+            .grouping_by_saturating_summing(|number| number.0, |number| number.1)
+    );
+}
+
+#[test]
+fn test_grouping_by_averaging_pairs() {
+    let pairs: HashMap<i32, (i32, usize)> = [(4, (4, 2)), (5, (13, 1)), (18, (9, 1))]
+        .iter()
+        .cloned()
+        .collect();
+
+    assert_eq!(
+        pairs,
+        POINT_ARRAY
+            .iter()
+            .grouping_by_averaging_pairs(|point| point.x, |point| point.y)
+    );
+}
+
+#[test]
+fn test_grouping_by_averaging_pairs_merge() {
+    fn merge(
+        mut a: HashMap<i32, (i32, usize)>,
+        b: HashMap<i32, (i32, usize)>,
+    ) -> HashMap<i32, (i32, usize)> {
+        b.into_iter().for_each(|(key, (sum, count))| {
+            let pair = a.entry(key).or_insert((0, 0));
+            pair.0 += sum;
+            pair.1 += count;
+        });
+        a
+    }
+
+    let merged = merge(
+        VECTOR_ARRAY[..2]
+            .iter()
+            .grouping_by_averaging_pairs(|vector| vector.y, |vector| vector.z),
+        VECTOR_ARRAY[2..]
+            .iter()
+            .grouping_by_averaging_pairs(|vector| vector.y, |vector| vector.z),
+    );
+
+    assert_eq!(
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_averaging_pairs(|vector| vector.y, |vector| vector.z),
+        merged
+    );
+    assert_eq!(7.0 / 3.0, merged[&2].0 as f64 / merged[&2].1 as f64);
+}
+
+#[test]
+fn test_merge_grouped_counts() {
+    let merged = merge_grouped(
+        POINT_ARRAY[..1].iter().counter(|point| point.x),
+        POINT_ARRAY[1..].iter().counter(|point| point.x),
+        |count_a, count_b| count_a + count_b,
+    );
+
+    assert_eq!(POINT_ARRAY.iter().counter(|point| point.x), merged);
+}
+
+#[test]
+fn test_merge_grouped_vecs() {
+    let merged = merge_grouped(
+        VECTOR_ARRAY[..3].iter().grouping_by(|vector| vector.y),
+        VECTOR_ARRAY[3..].iter().grouping_by(|vector| vector.y),
+        |mut group_a, group_b| {
+            group_a.extend(group_b);
+            group_a
+        },
+    );
+
+    assert_eq!(VECTOR_ARRAY.iter().grouping_by(|vector| vector.y), merged);
+}
+
+#[test]
+fn test_grouping_by_bitmap() {
+    assert_eq!(
+        vec![
+            vec![&POINT_ARRAY[3]],
+            vec![&POINT_ARRAY[0], &POINT_ARRAY[1]],
+            vec![&POINT_ARRAY[2]],
+        ],
+        POINT_ARRAY
+            .iter()
+            .grouping_by_bitmap(|point| point.x as usize % 3, 2)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_grouping_by_bitmap_key_too_big() {
+    POINT_ARRAY
+        .iter()
+        .grouping_by_bitmap(|point| point.x as usize, 5);
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn test_grouping_by_smallvec() {
+    let points_grouped = POINT_ARRAY
+        .iter()
+        .grouping_by_smallvec::<1, _, _>(|point| point.x);
+
+    assert_eq!(3, points_grouped.len());
+    assert_eq!(&[&Point { x: 5, y: 13 }], points_grouped[&5].as_slice());
+    assert!(!points_grouped[&5].spilled());
+    assert_eq!(
+        &[&Point { x: 4, y: 2 }, &Point { x: 4, y: 2 }],
+        points_grouped[&4].as_slice()
+    );
+    assert!(points_grouped[&4].spilled());
+}
+
+#[test]
+fn test_grouping_by_crosstab() {
+    let crosstab = VECTOR_ARRAY
+        .iter()
+        .grouping_by_crosstab(|vector| vector.x, |vector| vector.y);
+
+    assert_eq!(1, crosstab.count(&1, &2));
+    assert_eq!(1, crosstab.count(&1, &3));
+    assert_eq!(2, crosstab.count(&2, &2));
+    assert_eq!(0, crosstab.count(&2, &3));
+    assert_eq!(0, crosstab.count(&7, &2));
+
+    assert_eq!(2, crosstab.row_total(&1));
+    assert_eq!(2, crosstab.row_total(&2));
+    assert_eq!(3, crosstab.col_total(&2));
+    assert_eq!(1, crosstab.col_total(&3));
+
+    assert_eq!(VECTOR_ARRAY.len(), crosstab.grand_total());
+    assert_eq!(
+        crosstab.grand_total(),
+        crosstab.row_total(&1) + crosstab.row_total(&2)
+    );
+    assert_eq!(
+        crosstab.grand_total(),
+        crosstab.col_total(&2) + crosstab.col_total(&3)
+    );
+}
+
+#[test]
+fn test_grouping_by_first_n() {
+    let first = [
+        (2, vec![&VECTOR_ARRAY[0], &VECTOR_ARRAY[2]]),
+        (3, vec![&VECTOR_ARRAY[1]]),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<i32, Vec<&Vector>>>();
+
+    assert_eq!(
+        first,
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_first_n(|vector| vector.y, 2)
+    );
+}
+
+#[test]
+fn test_grouping_by_first_n_zero() {
+    let first = VECTOR_ARRAY
+        .iter()
+        .grouping_by_first_n(|vector| vector.y, 0);
+
+    assert_eq!(2, first.len());
+    assert!(first.values().all(Vec::is_empty));
+}
+
+#[test]
+fn test_grouping_by_last_n() {
+    let last = [
+        (2, vec![&VECTOR_ARRAY[2], &VECTOR_ARRAY[3]]),
+        (3, vec![&VECTOR_ARRAY[1]]),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<i32, Vec<&Vector>>>();
+
+    assert_eq!(
+        last,
+        VECTOR_ARRAY.iter().grouping_by_last_n(|vector| vector.y, 2)
+    );
+}
+
+#[test]
+fn test_grouping_by_last_n_zero() {
+    let last = VECTOR_ARRAY.iter().grouping_by_last_n(|vector| vector.y, 0);
+
+    assert_eq!(2, last.len());
+    assert!(last.values().all(Vec::is_empty));
+}
+
+#[test]
+fn test_grouping_by_set_with_count() {
+    let grouped = POINT_ARRAY
+        .iter()
+        .grouping_by_set_with_count(|point| point.x);
+
+    assert_eq!(
+        (
+            [&Point { x: 4, y: 2 }]
+                .iter()
+                .cloned()
+                .collect::<HashSet<&Point>>(),
+            2
+        ),
+        grouped[&4]
+    );
+    assert_eq!(1, grouped[&5].1);
+    assert_eq!(grouped[&18].0.len(), grouped[&18].1);
+}
+
+#[test]
+fn test_grouping_by_argmax_sum() {
+    assert_eq!(
+        Some((2, 7)),
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_argmax_sum(|vector| vector.y, |vector| vector.z)
+    );
+    // Both groups sum 3, but "b" reaches it first
+    assert_eq!(
+        Some(("b", 3)),
+        [("a", 2), ("b", 3), ("a", 1)]
+            .iter()
+            .grouping_by_argmax_sum(|pair| pair.0, |pair| pair.1)
+    );
+    assert_eq!(
+        None,
+        VECTOR_ARRAY[..0]
+            .iter()
+            .grouping_by_argmax_sum(|vector| vector.x, |vector| vector.z)
+    );
+}
+
+#[test]
+fn test_grouping_by_ratio_to_max() {
+    let ratios = [(2, vec![1.0, 0.5, 0.25]), (3, vec![1.0])]
+        .iter()
+        .cloned()
+        .collect::<HashMap<i32, Vec<f64>>>();
+
+    assert_eq!(
+        ratios,
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_ratio_to_max(|vector| vector.y, |vector| vector.z as f64)
+    );
+}
+
+#[test]
+fn test_grouping_by_mapping_into_existing() {
+    let mut points_y = [(4, vec![0])]
+        .iter()
+        .cloned()
+        .collect::<HashMap<i32, Vec<i32>>>();
+    POINT_ARRAY[..1].iter().grouping_by_mapping_into_existing(
+        &mut points_y,
+        |point| point.x,
+        |point| point.y,
+    );
+    POINT_ARRAY[1..].iter().grouping_by_mapping_into_existing(
+        &mut points_y,
+        |point| point.x,
+        |point| point.y,
+    );
+
+    assert_eq!(
+        [(4, vec![0, 2, 2]), (5, vec![13]), (18, vec![9])]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i32, Vec<i32>>>(),
+        points_y
+    );
+}
+
+#[test]
+fn test_grouping_by_reduce() {
+    let sums = [1, 2, 3, 4, 5, 6, 7]
+        .iter()
+        .cloned()
+        .grouping_by_reduce(|number| number % 2 == 0, |a, b| a + b);
+
+    assert_eq!(
+        [(true, 12), (false, 16)]
+            .iter()
+            .cloned()
+            .collect::<HashMap<bool, i32>>(),
+        sums
+    );
+}
+
+#[test]
+fn grouping_by_max_all_by_key() {
+    let a = VECTOR_ARRAY
+        .iter()
+        .grouping_by_max_all_by_key(|vector| vector.y, |vector| vector.x);
+    assert_eq!(
+        a,
+        [
+            (
+                2,
+                vec![&Vector { x: 2, y: 2, z: 2 }, &Vector { x: 2, y: 2, z: 1 }]
+            ),
+            (3, vec![&Vector { x: 1, y: 3, z: 3 }]),
+        ]
+        .iter()
+        .cloned()
+        .collect()
+    )
+}
+
+#[test]
+fn test_counter_percentages() {
+    assert_eq!(
+        [(4, 50), (5, 25), (18, 25)]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i32, u8>>(),
+        POINT_ARRAY.iter().counter_percentages(|point| point.x)
+    );
+
+    // 1/3 of the items each, rounded to 33, so they sum 99
+    let percentages = [1, 2, 3].iter().counter_percentages(|&&x| x);
+    assert!(percentages.values().all(|&percentage| percentage == 33));
+
+    assert!(POINT_ARRAY[..0]
+        .iter()
+        .counter_percentages(|point| point.x)
+        .is_empty());
+}
+
+#[test]
+fn test_grouping_by_join_with() {
+    let joined = POINT_ARRAY.iter().grouping_by_join_with(
+        |point| point.y > 5,
+        |point| point.x,
+        "[",
+        ", ",
+        "]",
+    );
+
+    assert_eq!(
+        [(false, "[4, 4]".to_string()), (true, "[5, 18]".to_string())]
+            .iter()
+            .cloned()
+            .collect::<HashMap<bool, String>>(),
+        joined
+    );
+}
+
+#[test]
+fn test_grouping_by_count_where() {
+    let counts = POINT_ARRAY.iter().grouping_by_count_where(
+        |point| point.x % 2 == 0,
+        &[
+            ("high", Box::new(|point: &&Point| point.y > 5)),
+            ("diagonal", Box::new(|point: &&Point| point.x == point.y)),
+        ],
+    );
+
+    assert_eq!(1, counts[&true]["high"]);
+    assert_eq!(0, counts[&true]["diagonal"]);
+    assert_eq!(1, counts[&false]["high"]);
+    assert_eq!(0, counts[&false]["diagonal"]);
+}
+
+#[test]
+fn test_grouping_by_ranked_by_size() {
+    assert_eq!(
+        vec![
+            (
+                2,
+                vec![&VECTOR_ARRAY[0], &VECTOR_ARRAY[2], &VECTOR_ARRAY[3]]
+            ),
+            (3, vec![&VECTOR_ARRAY[1]]),
+        ],
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_ranked_by_size(|vector| vector.y)
+    );
+    assert_eq!(
+        vec![
+            (4, vec![&POINT_ARRAY[0], &POINT_ARRAY[1]]),
+            (5, vec![&POINT_ARRAY[2]]),
+            (18, vec![&POINT_ARRAY[3]]),
+        ],
+        POINT_ARRAY
+            .iter()
+            .grouping_by_ranked_by_size(|point| point.x)
+    );
+}
+
+#[test]
+fn test_grouping_by_as_btreeset() {
+    let numbers_grouped = [5i8, -3, 2, -3, 7, 1, 2]
+        .iter()
+        .grouping_by_as_btreeset(|number| number.signum());
+
+    assert_eq!(
+        vec![&1, &2, &5, &7],
+        numbers_grouped[&1].iter().cloned().collect::<Vec<&i8>>()
+    );
+    assert_eq!(
+        vec![&-3],
+        numbers_grouped[&-1].iter().cloned().collect::<Vec<&i8>>()
+    );
+}
+
+#[test]
+fn test_grouping_by_as_vecdeque() {
+    let mut queues = VECTOR_ARRAY
+        .iter()
+        .grouping_by_as_vecdeque(|vector| vector.y);
+
+    let group = queues.get_mut(&2).unwrap();
+    assert_eq!(Some(&&Vector { x: 1, y: 2, z: 4 }), group.front());
+    assert_eq!(Some(&&Vector { x: 2, y: 2, z: 1 }), group.back());
+    assert_eq!(Some(&Vector { x: 1, y: 2, z: 4 }), group.pop_front());
+    assert_eq!(Some(&&Vector { x: 2, y: 2, z: 2 }), group.front());
+    assert_eq!(1, queues[&3].len());
+}
+
+#[test]
+fn test_grouping_by_then_averaging() {
+    let means = [
+        (1, [(2, 4.0), (3, 3.0)].iter().cloned().collect()),
+        (2, [(2, 1.5)].iter().cloned().collect()),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<i32, HashMap<i32, f64>>>();
+
+    assert_eq!(
+        means,
+        VECTOR_ARRAY.iter().grouping_by_then_averaging(
+            |vector| vector.x,
+            |vector| vector.y,
+            |vector| vector.z as f64
+        )
+    );
+}
+
+#[test]
+fn test_grouping_by_with_finisher() {
+    let mut calls = 0;
+    let firsts = [4, 9, 2, 7, 1, 8].iter().grouping_by_with_finisher(
+        |&&x| x % 2,
+        |mut group| {
+            calls += 1;
+            group.sort();
+            group[0]
+        },
+    );
+
+    assert_eq!(2, calls);
+    assert_eq!(
+        [(0, &2), (1, &1)]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i32, &i32>>(),
+        firsts
+    );
+}
+
+#[test]
+fn test_grouping_by_smallest_group() {
+    assert_eq!(
+        Some((5, 1)),
+        [4, 5, 4, 18, 18, 4]
+            .iter()
+            .grouping_by_smallest_group(|&&x| x)
+    );
+    assert_eq!(
+        None,
+        POINT_ARRAY[..0]
+            .iter()
+            .grouping_by_smallest_group(|point| point.x)
+    );
+}
+
+#[test]
+fn test_grouping_by_smallest_group_tie() {
+    // Both 3 and 2 have one item, but 3 appears first
+    assert_eq!(
+        Some((3, 1)),
+        [1, 3, 1, 2].iter().grouping_by_smallest_group(|&&x| x)
+    );
+}
+
+#[test]
+fn test_grouping_by_running_max() {
+    let running = [(1, 5), (1, 3), (2, 1), (1, 8), (1, 8), (1, 2)]
+        .iter()
+        .grouping_by_running_max(|pair| pair.0, |pair| pair.1);
+
+    assert_eq!(vec![5, 5, 8, 8, 8], running[&1]);
+    assert!(running[&1].windows(2).all(|pair| pair[0] <= pair[1]));
+    assert_eq!(vec![1], running[&2]);
+}
+
+#[test]
+fn test_grouping_by_diff() {
+    let diffs = [(1, 2), (2, 7), (1, 3), (1, 7), (1, 12)]
+        .iter()
+        .grouping_by_diff(|pair| pair.0, |pair| pair.1);
+
+    assert_eq!(vec![1, 4, 5], diffs[&1]);
+    assert!(diffs[&2].is_empty());
+}
+
+#[test]
+fn test_grouping_by_collect_result() {
+    let sums = POINT_ARRAY.iter().grouping_by_collect_result(
+        |point| point.x,
+        |group| {
+            group.iter().try_fold(0i8, |acc, point| {
+                acc.checked_add(point.y as i8).ok_or(point.x)
+            })
+        },
+    );
+
+    assert_eq!(
+        Ok([(4, 4), (5, 13), (18, 9)]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i32, i8>>()),
+        sums
+    );
+}
+
+#[test]
+fn test_grouping_by_collect_result_failure() {
+    // Only the group of 1 has more than one item, so it's the only one which fails
+    let result = [3, 1, 4, 1, 5].iter().grouping_by_collect_result(
+        |&&x| x,
+        |group| {
+            if group.len() > 1 {
+                Err(*group[0])
+            } else {
+                Ok(group.len())
+            }
+        },
+    );
+
+    assert_eq!(Err(1), result);
+}
+
+#[test]
+fn test_grouping_by_collect_result_earliest_failure() {
+    // Every group fails, 3 is the key which appears first
+    let mut collected = 0;
+    let result = [3, 1, 4, 1, 5, 9, 2, 6].iter().grouping_by_collect_result(
+        |&&x| x,
+        |group| {
+            collected += 1;
+            Err::<(), i32>(*group[0])
+        },
+    );
+
+    assert_eq!(Err(3), result);
+    assert_eq!(1, collected);
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct CheckedSum(i8);
+
+impl TryAccumulator<&Point> for CheckedSum {
+    type Error = i32;
+
+    fn try_accept(&mut self, point: &&Point) -> Result<(), i32> {
+        self.0 = self.0.checked_add(point.y as i8).ok_or(point.x)?;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_grouping_by_try_aggregate() {
+    let sums = POINT_ARRAY
+        .iter()
+        .grouping_by_try_aggregate::<_, CheckedSum, _>(|point| point.x);
+
+    assert_eq!(
+        Ok(
+            vec![(4, CheckedSum(4)), (5, CheckedSum(13)), (18, CheckedSum(9))]
+                .into_iter()
+                .collect::<HashMap<i32, CheckedSum>>()
+        ),
+        sums
+    );
+}
+
+#[test]
+fn test_grouping_by_try_aggregate_failure() {
+    let points = [Point { x: 1, y: 100 }, Point { x: 1, y: 100 }];
+    let sums = points
+        .iter()
+        .grouping_by_try_aggregate::<_, CheckedSum, _>(|point| point.x);
+
+    assert_eq!(Err(1), sums);
+}
+
+#[test]
+fn test_grouping_by_windows() {
+    let windows = [1, 2, 3, 4, 5, 6]
+        .iter()
+        .grouping_by_windows(|&&x| x < 6, 2);
+
+    assert_eq!(
+        vec![vec![&1, &2], vec![&2, &3], vec![&3, &4], vec![&4, &5]],
+        windows[&true]
+    );
+    // A group smaller than the window size has no windows
+    assert!(windows[&false].is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_grouping_by_windows_zero() {
+    VECTOR_ARRAY
+        .iter()
+        .grouping_by_windows(|vector| vector.y, 0);
+}
+
+#[test]
+fn test_grouping_by_mode_all() {
+    // The group 2 has the x values 1, 2 and 2, and the z values 4, 2 and 1
+    let modes = VECTOR_ARRAY
+        .iter()
+        .grouping_by_mode_all(|vector| vector.y, |vector| vector.x);
+
+    assert_eq!(vec![2], modes[&2]);
+    assert_eq!(vec![1], modes[&3]);
+
+    let modes = VECTOR_ARRAY
+        .iter()
+        .grouping_by_mode_all(|vector| vector.y, |vector| vector.z);
+
+    assert_eq!(vec![4, 2, 1], modes[&2]);
+}
+
+#[test]
+fn test_grouping_by_mode_all_tie() {
+    let modes = ["b", "a", "c", "a", "b"]
+        .iter()
+        .grouping_by_mode_all(|_| 0, |&&s| s);
+
+    assert_eq!(vec!["b", "a"], modes[&0]);
+}
+
+#[derive(Default)]
+struct CountSum {
+    count: usize,
+    sum: i32,
+}
+
+impl Accumulator<&Vector> for CountSum {
+    fn accept(&mut self, vector: &&Vector) {
+        self.count += 1;
+        self.sum += vector.z;
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.count += other.count;
+        self.sum += other.sum;
+    }
+}
+
+#[test]
+fn test_grouping_by_aggregate() {
+    let aggregates = VECTOR_ARRAY
+        .iter()
+        .grouping_by_aggregate::<_, CountSum, _>(|vector| vector.y);
+
+    assert_eq!((3, 7), (aggregates[&2].count, aggregates[&2].sum));
+    assert_eq!((1, 3), (aggregates[&3].count, aggregates[&3].sum));
+
+    // Merging the partial aggregations of every group gives the aggregation of the whole iterator
+    let mut merged = CountSum::default();
+    aggregates.into_values().for_each(|acc| merged.merge(acc));
+    assert_eq!((4, 10), (merged.count, merged.sum));
+}
+
+#[test]
+fn test_grouping_by_aggregate_builtin() {
+    let counts = POINT_ARRAY
+        .iter()
+        .grouping_by_aggregate::<_, CountAcc, _>(|point| point.x);
+    let sums = [(4, 2), (5, 13), (4, 2)]
+        .iter()
+        .map(|pair| pair.1)
+        .grouping_by_aggregate::<_, SumAcc<i32>, _>(|&y| y > 5);
+
+    assert_eq!(2, counts[&4].count);
+    assert_eq!(SumAcc { sum: 4 }, sums[&false]);
+    assert_eq!(SumAcc { sum: 13 }, sums[&true]);
+}
+
+#[test]
+fn test_grouping_by_first_where() {
+    // No point with x == 4 has y > 5
+    assert_eq!(
+        [(5, &Point { x: 5, y: 13 }), (18, &Point { x: 18, y: 9 })]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i32, &Point>>(),
+        POINT_ARRAY
+            .iter()
+            .grouping_by_first_where(|point| point.x, |point| point.y > 5)
+    );
+    assert_eq!(
+        [(2, &VECTOR_ARRAY[2]), (3, &VECTOR_ARRAY[1])]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i32, &Vector>>(),
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_first_where(|vector| vector.y, |vector| vector.z < 4)
+    );
+}
+
+#[test]
+fn test_grouping_by_any() {
+    let mut calls = 0;
+    let any = POINT_ARRAY.iter().grouping_by_any(
+        |point| point.x,
+        |point| {
+            calls += 1;
+            point.y == 2
+        },
+    );
+
+    assert_eq!(
+        [(4, true), (5, false), (18, false)]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i32, bool>>(),
+        any
+    );
+    // The second point with x == 4 isn't checked
+    assert_eq!(3, calls);
+}
+
+#[test]
+fn test_grouping_by_all() {
+    assert_eq!(
+        [(4, true), (5, false), (18, true)]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i32, bool>>(),
+        POINT_ARRAY
+            .iter()
+            .grouping_by_all(|point| point.x, |point| point.y < 10)
+    );
+}
+
+#[test]
+fn test_grouping_by_summing_kahan() {
+    let values = std::iter::once(1e8)
+        .chain(std::iter::repeat_n(0.1, 1000))
+        .map(|value| (0, value))
+        .collect::<Vec<(i32, f64)>>();
+
+    let naive = values.iter().summing(|pair| pair.0, |pair| pair.1);
+    let kahan = values
+        .iter()
+        .grouping_by_summing_kahan(|pair| pair.0, |pair| pair.1);
+
+    assert!((naive[&0] - 100000100.0).abs() > 1e-7);
+    assert!((kahan[&0] - 100000100.0).abs() < 1e-7);
+}
+
+#[test]
+fn test_grouping_by_span() {
+    assert_eq!(
+        [(2, (1, 4, 3)), (3, (3, 3, 0))]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i32, (i32, i32, i32)>>(),
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_span(|vector| vector.y, |vector| vector.z)
+    );
+}
+
+#[test]
+fn test_grouping_by_count_matching() {
+    assert_eq!(
+        [(2, 1), (3, 1)]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i32, usize>>(),
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_count_matching(|vector| vector.y, |vector| vector.z > 2)
+    );
+    // The only vector with y == 3 has z == 3, so its group is kept with a count of 0 for z > 3
+    assert_eq!(
+        [(2, 1), (3, 0)]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i32, usize>>(),
+        VECTOR_ARRAY
+            .iter()
+            .grouping_by_count_matching(|vector| vector.y, |vector| vector.z > 3)
+    );
+}
+
+#[test]
+fn test_interleave_groups() {
+    let groups = [("b", vec![4]), ("a", vec![1, 2, 3]), ("c", vec![5, 6])]
+        .iter()
+        .cloned()
+        .collect::<HashMap<&str, Vec<i32>>>();
+
+    assert_eq!(vec![1, 4, 5, 2, 6, 3], interleave_groups(groups));
+    assert!(interleave_groups(HashMap::<i32, Vec<i32>>::new()).is_empty());
+}
+
+#[test]
+fn test_counter_cumulative() {
+    // 1 and 3 are tied, but 3 appears first
+    assert_eq!(
+        vec![(5, 3, 3), (3, 2, 5), (1, 2, 7), (4, 1, 8)],
+        [3, 1, 4, 1, 5, 5, 3, 5].iter().counter_cumulative(|&&x| x)
+    );
+    assert!(std::iter::empty::<i32>()
+        .counter_cumulative(|&x| x)
+        .is_empty());
+}
+
+#[test]
+fn test_map_keys() {
+    let groups = [
+        "Apple",
+        "avocado",
+        "banana",
+        "apricot",
+        "Blueberry",
+        "cherry",
+    ]
+    .iter()
+    .grouping_by(|word| word.chars().next().unwrap());
+
+    let mut groups = map_keys(
+        groups,
+        |initial| initial.to_ascii_lowercase(),
+        |mut group1, group2| {
+            group1.extend(group2);
+            group1
+        },
+    );
+    groups.values_mut().for_each(|group| group.sort());
+
+    assert_eq!(
+        [
+            ('a', vec![&"Apple", &"apricot", &"avocado"]),
+            ('b', vec![&"Blueberry", &"banana"]),
+            ('c', vec![&"cherry"]),
+        ]
+        .iter()
+        .cloned()
+        .collect::<HashMap<char, Vec<&&str>>>(),
+        groups
+    );
+}
+
+#[test]
+fn test_grouping_by_then_max() {
+    let vectors = [
+        Vector { x: 1, y: 2, z: 1 },
+        Vector { x: 3, y: 2, z: 1 },
+        Vector { x: 2, y: 2, z: 1 },
+        Vector { x: 5, y: 2, z: 7 },
+        Vector { x: 4, y: 3, z: 1 },
+    ];
+
+    let maxima = vectors.iter().grouping_by_then_max(
+        |vector| vector.y,
+        |vector| vector.z,
+        |vector1, vector2| vector1.x.cmp(&vector2.x),
+    );
+
+    assert_eq!(
+        [
+            (
+                2,
+                [(1, &vectors[1]), (7, &vectors[3])]
+                    .iter()
+                    .cloned()
+                    .collect()
+            ),
+            (3, [(1, &vectors[4])].iter().cloned().collect()),
+        ]
+        .iter()
+        .cloned()
+        .collect::<HashMap<i32, HashMap<i32, &Vector>>>(),
+        maxima
+    );
+}
+
+#[test]
+fn test_grouping_by_top_k_groups() {
+    let points_grouped = [
+        (4, vec![&Point { x: 4, y: 2 }, &Point { x: 4, y: 2 }]),
+        (5, vec![&Point { x: 5, y: 13 }]),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<i32, Vec<&Point>>>();
+
+    // 5 and 18 have one point each, but 5 appears first
+    assert_eq!(
+        points_grouped,
+        POINT_ARRAY
+            .iter()
+            .grouping_by_top_k_groups(|point| point.x, 2)
+    );
+    assert!(POINT_ARRAY
+        .iter()
+        .grouping_by_top_k_groups(|point| point.x, 0)
+        .is_empty());
+}
+
+#[test]
+fn test_grouping_by_with_other() {
+    let groups = [1, 2, 2, 3, 4, 5, 2, 1]
+        .iter()
+        .grouping_by_with_other(|&&x| x, 2, 0);
+
+    assert_eq!(
+        [
+            (2, vec![&2, &2, &2]),
+            (1, vec![&1, &1]),
+            (0, vec![&3, &4, &5])
+        ]
+        .iter()
+        .cloned()
+        .collect::<HashMap<i32, Vec<&i32>>>(),
+        groups
+    );
+    // Without groups left out there's no `other` group
+    assert!(!POINT_ARRAY
+        .iter()
+        .grouping_by_with_other(|point| point.x, 3, 0)
+        .contains_key(&0));
+}
+
+#[test]
+fn test_grouping_by_with_other_collision() {
+    // `other` is 2, which is one of the kept keys
+    let groups = [1, 2, 2, 3, 1, 1]
+        .iter()
+        .grouping_by_with_other(|&&x| x, 2, 2);
+
+    assert_eq!(vec![&1, &1, &1], groups[&1]);
+    assert_eq!(vec![&2, &2, &3], groups[&2]);
+    assert_eq!(2, groups.len());
+}
+
+#[test]
+fn test_grouping_by_iqr() {
+    // The first group is 1..=9, with Q1 = 3 and Q3 = 7
+    let iqrs = (1..=9)
+        .chain(std::iter::once(100))
+        .grouping_by_iqr(|&x| x < 100, |&x| x as f64);
+
+    assert_eq!(4.0, iqrs[&true]);
+    assert_eq!(0.0, iqrs[&false]);
+}
+
+#[test]
+fn test_grouping_by_outliers() {
+    // The group of the evens is -40, 2, 4, ..., 18, with Q1 = 4.5 and Q3 = 13.5, so the fences are -9 and 27
+    let outliers = (1..20)
+        .chain(std::iter::once(-40))
+        .grouping_by_outliers(|&x| x % 2 == 0, |&x| x as f64);
+
+    assert_eq!(
+        [(true, vec![-40])]
+            .iter()
+            .cloned()
+            .collect::<HashMap<bool, Vec<i32>>>(),
+        outliers
+    );
+}
+
+#[test]
+fn test_counter_normalized() {
+    let scaled = [3, 1, 4, 1, 5, 9, 2]
+        .iter()
+        .counter_normalized(|&&x| x % 3, 70.0);
+
+    assert!((scaled[&0] - 20.0).abs() < 1e-10);
+    assert!((scaled[&1] - 30.0).abs() < 1e-10);
+    assert!((scaled[&2] - 20.0).abs() < 1e-10);
+    assert!((scaled.values().sum::<f64>() - 70.0).abs() < 1e-10);
+    assert!(std::iter::empty::<i32>()
+        .counter_normalized(|&x| x, 1.0)
+        .is_empty());
+}
+
+#[test]
+fn test_transpose_groups() {
+    let groups = [(1, vec!['a', 'b']), (2, vec!['b', 'c', 'b'])]
+        .iter()
+        .cloned()
+        .collect::<HashMap<i32, Vec<char>>>();
+
+    let mut transposed = transpose_groups(groups);
+    transposed.values_mut().for_each(|keys| keys.sort());
+
+    // 'b' is twice in the group 2, so it has the key 2 twice
+    assert_eq!(
+        [('a', vec![1]), ('b', vec![1, 2, 2]), ('c', vec![2])]
+            .iter()
+            .cloned()
+            .collect::<HashMap<char, Vec<i32>>>(),
+        transposed
+    );
+}
+
+#[test]
+fn test_grouping_by_numeric_summary() {
+    // The group 2 has the z values 4, 2 and 1
+    let summaries = VECTOR_ARRAY
+        .iter()
+        .grouping_by_numeric_summary(|vector| vector.y, |vector| vector.z as f64);
+
+    let summary = &summaries[&2];
+    assert_eq!(3, summary.count);
+    assert_eq!(1.0, summary.min);
+    assert_eq!(4.0, summary.max);
+    assert_eq!(7.0, summary.sum);
+    assert!((summary.mean - 7.0 / 3.0).abs() < 1e-10);
+    assert!((summary.m2 - 14.0 / 3.0).abs() < 1e-10);
+    assert!((summary.variance() - 14.0 / 9.0).abs() < 1e-10);
+    assert!((summary.stddev() - (14.0f64 / 9.0).sqrt()).abs() < 1e-10);
+
+    let summary = &summaries[&3];
+    assert_eq!((1, 3.0, 0.0), (summary.count, summary.mean, summary.m2));
+    assert_eq!(0.0, summary.stddev());
+}
+
+#[test]
+fn test_grouping_by_lazy() {
+    let points_grouped = POINT_ARRAY
+        .iter()
+        .grouping_by_lazy(|point| point.x)
+        .collect::<HashMap<i32, Vec<&Point>>>();
+
+    assert_eq!(
+        POINT_ARRAY.iter().grouping_by(|point| point.x),
+        points_grouped
+    );
+    assert_eq!(
+        3,
+        POINT_ARRAY.iter().grouping_by_lazy(|point| point.x).len()
+    );
+}
+
+#[test]
+fn test_grouping_by_mad() {
+    // Both groups have mean 5, the evens with absolute deviations 3, 1, 1 and 3
+    // and the odds with absolute deviations 4, 2, 0, 2 and 4
+    let deviations = (1..=9).grouping_by_mad(|&x| x % 2 == 0, |&x| x as f64);
+
+    assert_eq!(
+        [(true, 2.0), (false, 2.4)]
+            .iter()
+            .cloned()
+            .collect::<HashMap<bool, f64>>(),
+        deviations
+    );
+    assert_eq!(
+        0.0,
+        POINT_ARRAY
+            .iter()
+            .grouping_by_mad(|point| point.x, |point| point.y as f64)[&5]
+    );
+}
+
+#[test]
+fn test_grouping_by_resolve() {
+    let vectors = [
+        Vector { x: 1, y: 2, z: 4 },
+        Vector { x: 1, y: 3, z: 3 },
+        Vector { x: 1, y: 2, z: 5 },
+        Vector { x: 2, y: 2, z: 1 },
+    ];
+
+    assert_eq!(
+        [(1, &vectors[2]), (2, &vectors[3])]
+            .iter()
+            .cloned()
+            .collect::<HashMap<i32, &Vector>>(),
+        vectors.iter().grouping_by_resolve(
+            |vector| vector.x,
+            |old, new| if new.z > old.z { new } else { old }
+        )
+    );
+}
+
+#[test]
+fn test_grouping_by_resolve_combining() {
+    let points_summed = POINT_ARRAY.iter().cloned().grouping_by_resolve(
+        |point| point.x,
+        |old, new| Point {
+            x: old.x,
+            y: old.y + new.y,
+        },
+    );
+
+    assert_eq!(Point { x: 4, y: 4 }, points_summed[&4]);
+    assert_eq!(Point { x: 5, y: 13 }, points_summed[&5]);
+}
+
+#[test]
+fn test_grouping_by_counter_above() {
+    assert_eq!(
+        [(4, 2)].iter().cloned().collect::<HashMap<i32, usize>>(),
+        POINT_ARRAY
+            .iter()
+            .grouping_by_counter_above(|point| point.x, 2)
+    );
+    assert_eq!(
+        POINT_ARRAY.iter().counter(|point| point.x),
+        POINT_ARRAY
+            .iter()
+            .grouping_by_counter_above(|point| point.x, 0)
+    );
+    assert!(POINT_ARRAY
+        .iter()
+        .grouping_by_counter_above(|point| point.x, 3)
+        .is_empty());
+}
+
+#[test]
+fn test_grouping_by_histogram_non_finite() {
+    let (edges, counts) = [1.0, f64::NAN, 3.0, f64::INFINITY, f64::NEG_INFINITY]
+        .iter()
+        .grouping_by_histogram(|&&value| value, 2);
+
+    assert_eq!(vec![(1.0, 2.0), (2.0, 3.0)], edges);
+    assert_eq!(
+        [(0, 1), (1, 1)]
+            .iter()
+            .cloned()
+            .collect::<HashMap<usize, usize>>(),
+        counts
+    );
+}
@@ -0,0 +1,85 @@
+// These tests only use the methods available without the `std` feature,
+// run them with `cargo test --no-default-features` to check the `no_std` build.
+
+use grouping_by::GroupingBy;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+const POINT_ARRAY: [Point; 4] = [
+    Point { x: 5, y: 13 },
+    Point { x: 4, y: 2 },
+    Point { x: 18, y: 9 },
+    Point { x: 4, y: 2 },
+];
+
+#[test]
+fn test_grouping_by_ordered() {
+    let points_grouped = POINT_ARRAY.iter().grouping_by_ordered(|point| point.x);
+
+    assert_eq!(
+        vec![
+            (4, vec![&Point { x: 4, y: 2 }, &Point { x: 4, y: 2 }]),
+            (5, vec![&Point { x: 5, y: 13 }]),
+            (18, vec![&Point { x: 18, y: 9 }]),
+        ],
+        points_grouped.into_iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_counter_ordered() {
+    let numbers_counted = [3i8, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5]
+        .iter()
+        .counter_ordered(|&&x| x);
+
+    assert_eq!(
+        numbers_counted.into_iter().collect::<Vec<(i8, usize)>>(),
+        vec![(1, 2), (2, 1), (3, 2), (4, 1), (5, 3), (6, 1), (9, 1)]
+    )
+}
+
+#[test]
+fn test_grouping_by_sorted_runs() {
+    let runs = POINT_ARRAY
+        .iter()
+        .grouping_by_sorted_runs(|point| point.x)
+        .collect::<Vec<_>>();
+
+    // The two points with x == 4 aren't contiguous, so each one starts its own run
+    assert_eq!(
+        vec![
+            (5, vec![&Point { x: 5, y: 13 }]),
+            (4, vec![&Point { x: 4, y: 2 }]),
+            (18, vec![&Point { x: 18, y: 9 }]),
+            (4, vec![&Point { x: 4, y: 2 }]),
+        ],
+        runs
+    );
+}
+
+#[test]
+fn test_grouping_by_sorted_runs_is_lazy() {
+    let mut runs = (0..).grouping_by_sorted_runs(|x| x / 3);
+
+    assert_eq!(Some((0, vec![0, 1, 2])), runs.next());
+    assert_eq!(Some((1, vec![3, 4, 5])), runs.next());
+}
+
+#[test]
+fn test_grouping_by_value_counts_ordered() {
+    let counts = POINT_ARRAY
+        .iter()
+        .grouping_by_value_counts_ordered(|point| point.x, |point| point.y);
+
+    assert_eq!(
+        vec![(4, vec![(2, 2)]), (5, vec![(13, 1)]), (18, vec![(9, 1)]),],
+        counts
+            .into_iter()
+            .map(|(x, ys)| (x, ys.into_iter().collect::<Vec<_>>()))
+            .collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "futures")]
+
+use std::collections::HashMap;
+
+use futures::executor::block_on;
+use futures::stream;
+use grouping_by::{GroupingBy, GroupingByStream};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+const POINT_ARRAY: [Point; 4] = [
+    Point { x: 4, y: 2 },
+    Point { x: 4, y: 3 },
+    Point { x: 5, y: 13 },
+    Point { x: 18, y: 9 },
+];
+
+#[test]
+fn test_grouping_by_stream() {
+    let points_grouped = [
+        (18, vec![Point { x: 18, y: 9 }]),
+        (5, vec![Point { x: 5, y: 13 }]),
+        (4, vec![Point { x: 4, y: 2 }, Point { x: 4, y: 3 }]),
+    ]
+    .iter()
+    .cloned()
+    .collect::<HashMap<i32, Vec<Point>>>();
+
+    assert_eq!(
+        points_grouped,
+        block_on(stream::iter(POINT_ARRAY.to_vec()).grouping_by_stream(|point| point.x))
+    );
+}
+
+#[test]
+fn test_grouping_by_stream_matches_iterator() {
+    assert_eq!(
+        POINT_ARRAY.iter().cloned().grouping_by(|point| point.y % 2),
+        block_on(stream::iter(POINT_ARRAY.to_vec()).grouping_by_stream(|point| point.y % 2))
+    );
+}